@@ -50,11 +50,55 @@
 //!
 //! ## Embedded DMA Support
 //!
-//! - The [RawBufSlice] structure implements the [embedded_dma::ReadBuffer] trait
-//! - The [RawBufSliceMut] structure implements the [embedded_dma::WriteBuffer] trait
+//! - The [RawSlice] structure implements the [embedded_dma::ReadBuffer] trait for all
+//!   [embedded_dma::Word] types, for example [RawBufSlice]
+//! - The [RawSliceMut] structure implements the [embedded_dma::WriteBuffer] and the
+//!   [embedded_dma::ReadBuffer] trait for all [embedded_dma::Word] types, for example
+//!   [RawBufSliceMut]
+//!
+//! ## Optional features
+//!
+//! - `alloc`: Adds `RawSlice::from_leaked_vec` which creates a raw slice from a leaked `Vec`.
+//! - `defmt`: Implements `defmt::Format` for the raw slice types. Only the address and the length
+//!   are formatted.
+//! - `serde`: Implements `serde::Serialize` and `serde::Deserialize` for the raw slice types.
+//!   Only the address and the length are (de)serialized. Please note that a deserialized raw
+//!   slice is very likely dangling, and dereferencing it is UB unless the address is still valid.
+//! - `bytemuck`: Adds `cast_pod` methods which reinterpret raw slices of `bytemuck::Pod` types
+//!   with size and alignment checks.
+//! - `zerocopy`: Adds `RawU8Slice::cast_from_bytes` which reinterprets a raw byte slice as a raw
+//!   slice of a `zerocopy::FromBytes` type with size and alignment checks.
+//! - `critical-section`: Adds the `CsRawSlice` type which stores a raw slice behind a
+//!   `critical_section::Mutex`.
+//! - `generation`: Adds the `GenRawSlice` type which tags a raw slice with a generation counter
+//!   to detect stale raw slices.
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use core::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    ptr::NonNull,
+};
+
+/// Raw slice which erases the lifetime of a borrowed slice.
+///
+/// The raw slice has the same size as a regular slice reference. It can also be NULL, and it is
+/// recommended to use this NULL state instead of wrapping the raw slice inside an [Option]:
+/// `Option<RawSlice<T>>` is larger than `RawSlice<T>` because the NULL state of the data pointer
+/// is a valid state and can not be used as a niche.
+///
+/// The [PartialEq] implementation compares the data pointer and the length, not the contents.
+/// Two raw slices pointing to distinct arrays with identical contents are therefore not equal.
+/// [Self::eq_slice] can be used to compare the contents instead. Similarly, the [Hash]
+/// implementation hashes the data pointer and the length, not the contents, and the [Ord]
+/// implementation orders by address and then by length instead of comparing lexicographically.
+#[derive(Debug, Copy, Clone)]
 pub struct RawSlice<T> {
     data: *const T,
     len: usize,
@@ -78,6 +122,135 @@ impl<T> RawSlice<T> {
         }
     }
 
+    /// Creates a new `RawSlice<T>` from a slice reference which only exposes the first `len`
+    /// elements.
+    ///
+    /// The stored length is clamped to the length of `data`, which prevents exposing elements past
+    /// the end of the original slice. This is useful for pre-sized buffers where the logical
+    /// payload is shorter than the allocation.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
+    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
+    pub const unsafe fn new_with_len(data: &[T], len: usize) -> Self {
+        let max_len = data.len();
+        Self {
+            data: data.as_ptr(),
+            len: if len < max_len { len } else { max_len },
+        }
+    }
+
+    /// Creates a new `RawSlice<T>` from a slice reference after checking that the slice lies
+    /// entirely within a permitted memory region.
+    ///
+    /// The memory region is described by the start address `region_start` and the length in
+    /// bytes `region_len`. This can be used to verify that a buffer is located inside a region
+    /// which is accessible by a DMA peripheral, for example one configured with an MPU.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
+    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [RegionError] if the byte range of the slice is not contained in the region.
+    pub unsafe fn new_within(
+        data: &[T],
+        region_start: *const u8,
+        region_len: usize,
+    ) -> Result<Self, RegionError> {
+        let start = data.as_ptr() as usize;
+        let region_start = region_start as usize;
+        let contained = match (
+            start.checked_add(core::mem::size_of_val(data)),
+            region_start.checked_add(region_len),
+        ) {
+            (Some(end), Some(region_end)) => start >= region_start && end <= region_end,
+            _ => false,
+        };
+        if !contained {
+            return Err(RegionError);
+        }
+        Ok(unsafe { Self::new(data) })
+    }
+
+    /// Creates a new `RawSlice<T>` from a raw pointer and a length.
+    ///
+    /// This is equivalent to [Self::new], but does not require an intermediate slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that `data` points to `len` valid and initialized elements
+    ///   which outlive this `RawSlice<T>`.
+    /// - The pointed-to data **must not** be mutated while this `RawSlice<T>` is used.
+    ///
+    /// If `data` is NULL, the raw slice is treated as NULL independently of `len`.
+    pub const unsafe fn from_raw_parts(data: *const T, len: usize) -> Self {
+        Self { data, len }
+    }
+
+    /// Creates a new `RawSlice<T>` from a raw pointer and a length after checking the alignment.
+    ///
+    /// This is equivalent to [Self::from_raw_parts], but returns [AlignError] if `data` is not
+    /// aligned to the alignment of `T`. A NULL pointer is always accepted.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for [Self::from_raw_parts] apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [AlignError] if `data` is not aligned to [core::mem::align_of] `T`.
+    pub unsafe fn from_raw_parts_aligned(data: *const T, len: usize) -> Result<Self, AlignError> {
+        if !data.is_null() && !data.is_aligned() {
+            return Err(AlignError);
+        }
+        Ok(Self { data, len })
+    }
+
+    /// Creates a new `RawSlice<T>` with a length of 1 from a reference to a single element.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the element outlives this `RawSlice<T>`.
+    /// - The original element **must not** be mutated while this `RawSlice<T>` is used.
+    pub const unsafe fn from_ref(value: &T) -> Self {
+        Self {
+            data: value as *const T,
+            len: 1,
+        }
+    }
+
+    /// Creates a new `RawSlice<T>` from an array reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the array outlives this `RawSlice<T>`.
+    /// - The original array **must not** be mutated while this `RawSlice<T>` is used.
+    pub const unsafe fn from_array<const N: usize>(arr: &[T; N]) -> Self {
+        Self {
+            data: arr.as_ptr(),
+            len: N,
+        }
+    }
+
+    /// Creates a new `RawSlice<T>` from a [alloc::vec::Vec] by leaking it.
+    ///
+    /// The memory is intentionally leaked and can not be reclaimed through this API. This is
+    /// mostly useful for host-side tests and tooling where cleanup does not matter. Because the
+    /// leaked memory is never freed and not accessible otherwise, this function is safe.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn from_leaked_vec(v: alloc::vec::Vec<T>) -> Self {
+        let leaked = v.leak();
+        Self {
+            data: leaked.as_ptr(),
+            len: leaked.len(),
+        }
+    }
+
     /// Creates an empty `RawSlice<T>`, equivalent to a null pointer with zero length.
     pub const fn new_nulled() -> Self {
         Self {
@@ -86,6 +259,18 @@ impl<T> RawSlice<T> {
         }
     }
 
+    /// Creates an empty `RawSlice<T>` with a dangling, non-NULL pointer.
+    ///
+    /// This mirrors how an empty slice reference is represented: [Self::is_null] returns
+    /// `false`, [Self::is_empty] returns `Some(true)` and [Self::get] returns an empty slice
+    /// instead of [None].
+    pub const fn dangling() -> Self {
+        Self {
+            data: NonNull::<T>::dangling().as_ptr(),
+            len: 0,
+        }
+    }
+
     /// Updates the raw pointer and length to point to a new slice.
     ///
     /// # Safety
@@ -103,6 +288,32 @@ impl<T> RawSlice<T> {
         self.len = 0;
     }
 
+    /// Takes the raw slice out, leaving a NULL raw slice in its place.
+    ///
+    /// This is similar to [Option::take].
+    pub const fn take(&mut self) -> RawSlice<T> {
+        let taken = RawSlice {
+            data: self.data,
+            len: self.len,
+        };
+        self.set_null();
+        taken
+    }
+
+    /// Updates the raw slice to point to a new slice and returns the previous raw slice.
+    ///
+    /// This is similar to [core::mem::replace].
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
+    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
+    pub const unsafe fn replace(&mut self, data: &[T]) -> RawSlice<T> {
+        let previous = self.take();
+        unsafe { self.set(data) };
+        previous
+    }
+
     /// Check whether the internal data pointer is NULL.
     pub const fn is_null(&self) -> bool {
         self.data.is_null()
@@ -110,7 +321,9 @@ impl<T> RawSlice<T> {
 
     /// Converts the raw pointer into a slice.
     ///
-    /// Returns [None] if the pointer is null.
+    /// Returns [None] if the pointer is null. A non-NULL raw slice with a length of 0, for
+    /// example one created with [Self::dangling], always returns an empty slice. This allows
+    /// distinguishing NULL, empty and non-empty raw slices.
     ///
     /// # Safety
     ///
@@ -123,6 +336,42 @@ impl<T> RawSlice<T> {
         Some(unsafe { core::slice::from_raw_parts(self.data, self.len) })
     }
 
+    /// Converts the raw pointer into a slice.
+    ///
+    /// This is equivalent to [Self::get], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn try_get(&self) -> Result<&[T], NullSliceError> {
+        match unsafe { self.get() } {
+            Some(slice) => Ok(slice),
+            None => Err(NullSliceError),
+        }
+    }
+
+    /// Converts the raw pointer into a slice, returning an empty slice if the pointer is null.
+    ///
+    /// This intentionally treats a NULL raw slice like an empty raw slice, which is useful for
+    /// callers which do not care about the distinction, for example in test code.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_or_empty(&self) -> &[T] {
+        match unsafe { self.get() } {
+            Some(slice) => slice,
+            None => &[],
+        }
+    }
+
     /// Returns [None] if the pointer is null and whether [Self::len] is 0 otherwise.
     pub const fn is_empty(&self) -> Option<bool> {
         if self.is_null() {
@@ -138,224 +387,5847 @@ impl<T> RawSlice<T> {
         }
         Some(self.len)
     }
-}
 
-impl<T> Default for RawSlice<T> {
-    fn default() -> Self {
-        Self::new_nulled()
+    /// Returns the length of the raw slice.
+    ///
+    /// This is equivalent to [Self::len], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    pub const fn try_len(&self) -> Result<usize, NullSliceError> {
+        if self.is_null() {
+            return Err(NullSliceError);
+        }
+        Ok(self.len)
     }
-}
 
-pub type RawBufSlice = RawU8Slice;
-pub type RawU8Slice = RawSlice<u8>;
-pub type RawU16Slice = RawSlice<u16>;
-pub type RawU32Slice = RawSlice<u32>;
+    /// Returns whether [Self::len] is 0.
+    ///
+    /// This is equivalent to [Self::is_empty], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    pub const fn try_is_empty(&self) -> Result<bool, NullSliceError> {
+        if self.is_null() {
+            return Err(NullSliceError);
+        }
+        Ok(self.len == 0)
+    }
 
-macro_rules! impl_dma_read_buf {
-    ($slice_type:ident, $ty:ident) => {
-        /// This allows using [Self] in DMA APIs which expect a [embedded_dma::ReadBuffer].
-        ///
-        /// However, the user still must ensure that any alignment rules for DMA buffers required by
-        /// the hardware are met and than any MPU/MMU configuration necessary is also performed for this
-        /// to work properly.
-        unsafe impl embedded_dma::ReadBuffer for $slice_type {
-            type Word = $ty;
-
-            unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
-                (self.data, self.len)
-            }
+    /// Consumes the raw slice and returns the raw pointer and the length.
+    ///
+    /// Returns a NULL pointer and a length of 0 if the raw slice is NULL. The returned parts can
+    /// be converted back with [Self::from_raw_parts].
+    pub const fn into_raw_parts(self) -> (*const T, usize) {
+        if self.is_null() {
+            return (core::ptr::null(), 0);
         }
-    };
-}
+        (self.data, self.len)
+    }
 
-impl_dma_read_buf!(RawBufSlice, u8);
-impl_dma_read_buf!(RawU16Slice, u16);
-impl_dma_read_buf!(RawU32Slice, u32);
+    /// Returns the raw data pointer, which might be NULL.
+    pub const fn as_ptr(&self) -> *const T {
+        self.data
+    }
 
-#[derive(Debug, Copy, Clone)]
-pub struct RawSliceMut<T> {
-    data: *mut T,
-    len: usize,
-}
+    /// Returns the raw data pointer as a [NonNull] pointer, or [None] if it is NULL.
+    pub const fn as_non_null(&self) -> Option<NonNull<T>> {
+        NonNull::new(self.data as *mut T)
+    }
 
-/// Safety: This type MUST be used with mutex to ensure concurrent access is valid.
-unsafe impl<T: Send> Send for RawSliceMut<T> {}
+    /// Returns the raw slice as a [NonNull] slice pointer which contains both the data pointer
+    /// and the length, or [None] if it is NULL.
+    pub const fn as_non_null_slice(&self) -> Option<NonNull<[T]>> {
+        match self.as_non_null() {
+            Some(data) => Some(NonNull::slice_from_raw_parts(data, self.len)),
+            None => None,
+        }
+    }
 
-impl<T> RawSliceMut<T> {
-    /// Creates a new `RawSlice<T>` from a slice reference.
+    /// Converts the raw pointer into a slice without checking whether the pointer is NULL.
     ///
     /// # Safety
     ///
-    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
-    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
-    #[allow(dead_code)]
-    pub const unsafe fn new(data: &mut [T]) -> Self {
-        Self {
-            data: data.as_mut_ptr(),
-            len: data.len(),
+    /// - The caller **must** ensure that the raw slice is not NULL. Calling this function on
+    ///   a NULL raw slice is UB.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_unchecked(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.data, self.len) }
+    }
+
+    /// Returns a reference to the element at position `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_elem(&self, index: usize) -> Option<&T> {
+        if self.data.is_null() || index >= self.len {
+            return None;
         }
+        Some(unsafe { &*self.data.add(index) })
     }
 
-    /// Creates an empty `RawSlice<T>`, equivalent to a null pointer with zero length.
-    pub const fn new_nulled() -> Self {
-        Self {
-            data: core::ptr::null_mut(),
-            len: 0,
+    /// Divides the raw slice into two raw slices at the index `mid`.
+    ///
+    /// The first raw slice contains the elements `[0, mid)` and the second raw slice contains
+    /// the elements `[mid, len)`. Returns [None] if the pointer is null or if `mid > len`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub const unsafe fn split_at(&self, mid: usize) -> Option<(RawSlice<T>, RawSlice<T>)> {
+        if self.data.is_null() || mid > self.len {
+            return None;
         }
+        Some((
+            RawSlice {
+                data: self.data,
+                len: mid,
+            },
+            RawSlice {
+                data: unsafe { self.data.add(mid) },
+                len: self.len - mid,
+            },
+        ))
     }
 
-    /// Updates the raw pointer and length to point to a new slice.
+    /// Divides the raw slice into two raw slices at the index `mid` without any checks.
+    ///
+    /// This is the unchecked variant of [Self::split_at].
     ///
     /// # Safety
     ///
-    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
-    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
-    pub const unsafe fn set(&mut self, data: &mut [T]) {
-        self.data = data.as_mut_ptr();
-        self.len = data.len();
+    /// - The caller **must** ensure that the pointer is not null and that `mid <= len`.
+    ///   Violating this results in UB.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub const unsafe fn split_at_unchecked(&self, mid: usize) -> (RawSlice<T>, RawSlice<T>) {
+        (
+            RawSlice {
+                data: self.data,
+                len: mid,
+            },
+            RawSlice {
+                data: unsafe { self.data.add(mid) },
+                len: unsafe { self.len.unchecked_sub(mid) },
+            },
+        )
     }
 
-    /// Converts the raw pointer into a slice.
+    /// Returns a raw slice containing the elements `[start, end)`.
     ///
-    /// Returns [None] if the pointer is null.
+    /// Returns [None] if the pointer is null, if `start > end` or if `end > len`.
     ///
     /// # Safety
     ///
     /// - The caller **must** ensure that the underlying memory is still valid.
-    /// - Using this function after the original slice is dropped results in UB.
-    pub const unsafe fn get<'slice>(&self) -> Option<&'slice [T]> {
-        if self.data.is_null() {
+    pub const unsafe fn subslice(&self, start: usize, end: usize) -> Option<RawSlice<T>> {
+        if self.data.is_null() || start > end || end > self.len {
             return None;
         }
-        Some(unsafe { core::slice::from_raw_parts(self.data, self.len) })
+        Some(RawSlice {
+            data: unsafe { self.data.add(start) },
+            len: end - start,
+        })
     }
 
-    /// Converts the raw pointer into a mutable slice.
+    /// Compares the contents of the raw slice with a regular slice.
     ///
-    /// Returns [None] if the pointer is null.
+    /// Unlike the [PartialEq] implementation which compares the data pointer and the length,
+    /// this compares the elements. Returns `false` if the pointer is null. This is provided as an
+    /// explicit `unsafe` method instead of a [PartialEq] implementation against slices, because
+    /// the comparison has to dereference the raw pointer.
     ///
     /// # Safety
     ///
     /// - The caller **must** ensure that the underlying memory is still valid.
     /// - Using this function after the original slice is dropped results in UB.
-    pub const unsafe fn get_mut<'slice>(&mut self) -> Option<&'slice mut [T]> {
-        if self.data.is_null() {
-            return None;
+    pub unsafe fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        match unsafe { self.get() } {
+            Some(slice) => slice == other,
+            None => false,
         }
-        Some(unsafe { core::slice::from_raw_parts_mut(self.data, self.len) })
-    }
-
-    pub const fn set_null(&mut self) {
-        self.data = core::ptr::null_mut();
-        self.len = 0;
     }
 
-    pub const fn is_null(&self) -> bool {
-        self.data.is_null()
+    /// Returns [None] if the pointer is null and the length of the raw slice in bytes otherwise.
+    ///
+    /// Also returns [None] if the calculation of the length in bytes overflows.
+    pub const fn byte_len(&self) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        self.len.checked_mul(core::mem::size_of::<T>())
     }
 
-    /// Returns [None] if the pointer is null and whether [Self::len] is 0 otherwise.
-    pub const fn is_empty(&self) -> Option<bool> {
+    /// Returns [None] if the pointer is null and whether the pointer is aligned to `align`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    pub fn is_aligned_to(&self, align: usize) -> Option<bool> {
+        assert!(align.is_power_of_two(), "alignment is not a power of two");
         if self.is_null() {
             return None;
         }
-        Some(self.len == 0)
+        Some(self.data as usize % align == 0)
     }
 
-    /// Returns [None] if the pointer is null and the length of the raw slice otherwise.
-    pub const fn len(&self) -> Option<usize> {
+    /// Returns [None] if the pointer is null and the alignment of the pointer otherwise.
+    ///
+    /// The alignment is the largest power of two which divides the address. This can be used to
+    /// verify the alignment before calling [Self::cast] or to diagnose misaligned DMA buffers.
+    pub fn alignment(&self) -> Option<usize> {
         if self.is_null() {
             return None;
         }
-        Some(self.len)
+        Some(1 << (self.data as usize).trailing_zeros())
     }
-}
 
-impl<T> Default for RawSliceMut<T> {
-    fn default() -> Self {
-        Self::new_nulled()
+    /// Reinterprets the raw slice as a raw slice of the element type `U`.
+    ///
+    /// The base pointer is kept and the length is recalculated as
+    /// `len * size_of::<T>() / size_of::<U>()`. A trailing partial element is truncated.
+    /// A NULL raw slice yields a NULL raw slice, and a NULL raw slice is also returned if the
+    /// length in bytes overflows a [usize].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` is a zero-sized type.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the pointer is properly aligned for `U`, which can be
+    ///   checked with [Self::alignment] or [Self::is_aligned_to].
+    /// - The caller **must** ensure that the underlying memory is a valid `[U]`.
+    pub const unsafe fn cast<U>(self) -> RawSlice<U> {
+        assert!(core::mem::size_of::<U>() != 0, "cast to zero-sized type");
+        match self.len.checked_mul(core::mem::size_of::<T>()) {
+            Some(byte_len) => RawSlice {
+                data: self.data as *const U,
+                len: byte_len / core::mem::size_of::<U>(),
+            },
+            None => RawSlice::new_nulled(),
+        }
     }
-}
 
-pub type RawBufSliceMut = RawU8SliceMut;
-pub type RawU8SliceMut = RawSliceMut<u8>;
-pub type RawU16SliceMut = RawSliceMut<u16>;
-pub type RawU32SliceMut = RawSliceMut<u32>;
+    /// Returns a raw byte slice covering the same memory region.
+    ///
+    /// A NULL raw slice yields a NULL raw byte slice, and a NULL raw byte slice is also returned
+    /// if the length in bytes overflows a [usize].
+    pub const fn as_bytes(&self) -> RawU8Slice {
+        match self.len.checked_mul(core::mem::size_of::<T>()) {
+            Some(len) => RawSlice {
+                data: self.data as *const u8,
+                len,
+            },
+            None => RawSlice::new_nulled(),
+        }
+    }
 
-macro_rules! impl_dma_write_buf {
-    ($slice_type:ident, $ty:ident) => {
-        /// This allows using [Self] in DMA APIs which expect a [embedded_dma::WriteBuffer].
-        ///
-        /// However, the user still must ensure that any alignment rules for DMA buffers required by
-        /// the hardware are met and than any MPU/MMU configuration necessary was also performed.
-        unsafe impl embedded_dma::WriteBuffer for $slice_type {
-            type Word = $ty;
-
-            unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
-                (self.data, self.len)
-            }
+    /// Shortens the raw slice to `new_len` elements.
+    ///
+    /// This has no effect if `new_len` is greater than or equal to the current length, or if the
+    /// pointer is null.
+    ///
+    /// In contrast to [Self::set], which replaces both the pointer and the length, this only
+    /// updates the length and can never grow the raw slice past its current length. This makes it
+    /// suitable for exposing a shorter logical length of a fixed allocation. Use
+    /// [Self::new_with_len] on the original allocation to expose a longer length again.
+    pub const fn truncate(&mut self, new_len: usize) {
+        if self.is_null() {
+            return;
         }
-    };
-}
+        if new_len < self.len {
+            self.len = new_len;
+        }
+    }
 
-impl_dma_write_buf!(RawBufSliceMut, u8);
-impl_dma_write_buf!(RawU16SliceMut, u16);
-impl_dma_write_buf!(RawU32SliceMut, u32);
+    /// Advances the start of the raw slice by `n` elements and reduces the length accordingly.
+    ///
+    /// Advancing by `n >= len` yields an empty raw slice pointing just past the end of the
+    /// original raw slice. This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within or one element past
+    ///   the end of the original allocation.
+    pub const unsafe fn advance(&mut self, n: usize) {
+        if self.is_null() {
+            return;
+        }
+        let n = if n > self.len { self.len } else { n };
+        self.data = unsafe { self.data.add(n) };
+        self.len -= n;
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Removes `front` elements from the start and `back` elements from the end of the raw
+    /// slice.
+    ///
+    /// This is equivalent to calling [Self::advance] with `front` and [Self::truncate] with the
+    /// remaining length minus `back`. If `front` and `back` exceed the length, the raw slice
+    /// becomes empty. This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within or one element past
+    ///   the end of the original allocation.
+    pub const unsafe fn shrink(&mut self, front: usize, back: usize) {
+        if self.is_null() {
+            return;
+        }
+        unsafe { self.advance(front) };
+        self.len = self.len.saturating_sub(back);
+    }
 
-    #[test]
-    pub fn test_basic() {
+    /// Returns a reference to the first element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn first(&self) -> Option<&T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &*self.data })
+    }
+
+    /// Returns a reference to the last element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn last(&self) -> Option<&T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &*self.data.add(self.len - 1) })
+    }
+
+    /// Returns an iterator over the elements of the raw slice.
+    ///
+    /// The iterator yields nothing if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime of
+    ///   the iterator.
+    /// - Using the iterator after the original slice is dropped results in UB.
+    pub unsafe fn iter(&self) -> RawSliceIter<'_, T> {
+        RawSliceIter::new(self.data, self.len)
+    }
+
+    /// Returns a [ValidRawSlice] which allows safe iteration for the lifetime `'slice`.
+    ///
+    /// A NULL raw slice yields a [ValidRawSlice] without any elements.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime
+    ///   `'slice`.
+    /// - The underlying memory **must not** be mutated during the lifetime `'slice`.
+    pub const unsafe fn assume_valid<'slice>(&self) -> ValidRawSlice<'slice, T> {
+        ValidRawSlice {
+            data: self.data,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns [None] if the pointer is null and a borrow-checked [RawSliceView] with the
+    /// lifetime `'slice` otherwise.
+    ///
+    /// This is the single unsafe step required to access the raw slice, all accesses through the
+    /// view are safe.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime
+    ///   `'slice`.
+    /// - The underlying memory **must not** be mutated during the lifetime `'slice`.
+    pub const unsafe fn view<'slice>(&self) -> Option<RawSliceView<'slice, T>> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(RawSliceView(unsafe {
+            core::slice::from_raw_parts(self.data, self.len)
+        }))
+    }
+
+    /// Returns the length of the raw slice, treating a NULL raw slice as empty.
+    pub const fn len_or_zero(&self) -> usize {
+        if self.is_null() {
+            return 0;
+        }
+        self.len
+    }
+
+    /// Returns whether the raw slice is empty, treating a NULL raw slice as empty.
+    pub const fn is_empty_or_true(&self) -> bool {
+        self.len_or_zero() == 0
+    }
+
+    /// Returns a raw slice containing the elements within `range`.
+    ///
+    /// All range types are supported, for example `raw.get_range(4..12)`, `raw.get_range(4..)`
+    /// or `raw.get_range(..=12)`. Returns [None] if the pointer is null, if the start of the
+    /// range is larger than the end, or if the end of the range is larger than the length.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub unsafe fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<RawSlice<T>> {
+        let (start, end) = range_to_start_end(range, self.len)?;
+        unsafe { self.subslice(start, end) }
+    }
+
+    /// Returns the first element and a raw slice containing the remaining elements.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn split_first(&self) -> Option<(&T, RawSlice<T>)> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some((
+            unsafe { &*self.data },
+            RawSlice {
+                data: unsafe { self.data.add(1) },
+                len: self.len - 1,
+            },
+        ))
+    }
+
+    /// Returns the last element and a raw slice containing the remaining elements.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn split_last(&self) -> Option<(&T, RawSlice<T>)> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some((
+            unsafe { &*self.data.add(self.len - 1) },
+            RawSlice {
+                data: self.data,
+                len: self.len - 1,
+            },
+        ))
+    }
+
+    /// Returns an iterator over raw slices of `chunk_size` elements.
+    ///
+    /// The last chunk might be shorter than `chunk_size`. The iterator yields nothing if the
+    /// pointer is null or if `chunk_size` is 0.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub unsafe fn chunks(&self, chunk_size: usize) -> RawSliceChunks<'_, T> {
+        let len = if self.is_null() || chunk_size == 0 {
+            0
+        } else {
+            self.len
+        };
+        RawSliceChunks {
+            remaining: RawSlice {
+                data: self.data,
+                len,
+            },
+            chunk_size: chunk_size.max(1),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over raw slices of exactly `chunk_size` elements.
+    ///
+    /// If the length is not a multiple of `chunk_size`, the trailing elements are skipped by the
+    /// iterator and can be retrieved with [RawSliceChunksExact::remainder]. The iterator yields
+    /// nothing if the pointer is null or if `chunk_size` is 0. In the latter case, the remainder
+    /// contains the whole raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub unsafe fn chunks_exact(&self, chunk_size: usize) -> RawSliceChunksExact<'_, T> {
+        let exact_len = if self.is_null() || chunk_size == 0 {
+            0
+        } else {
+            self.len - self.len % chunk_size
+        };
+        RawSliceChunksExact {
+            remaining: RawSlice {
+                data: self.data,
+                len: exact_len,
+            },
+            remainder: RawSlice {
+                data: self.data.wrapping_add(exact_len),
+                len: self.len - exact_len,
+            },
+            chunk_size: chunk_size.max(1),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns [None] if the pointer is null and whether `ptr` points to an element of the raw
+    /// slice otherwise.
+    ///
+    /// This only compares addresses, the data is never dereferenced.
+    pub fn contains_ptr(&self, ptr: *const T) -> Option<bool> {
+        if self.is_null() {
+            return None;
+        }
+        let start = self.as_ptr();
+        Some(ptr >= start && ptr < start.wrapping_add(self.len))
+    }
+
+    /// Returns the number of elements remaining from `current` to the end of the raw slice.
+    ///
+    /// Returns [None] if the pointer is null, if `current` is not within the range
+    /// `[data, data + len]` or if `current` does not point to an element boundary. This is useful
+    /// to calculate the remaining elements from a "current address" register of a DMA peripheral.
+    /// The data is never dereferenced.
+    pub fn remaining_from(&self, current: *const T) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        let start = self.as_ptr() as usize;
+        let current = current as usize;
+        let consumed_bytes = current.checked_sub(start)?;
+        let size = core::mem::size_of::<T>().max(1);
+        if consumed_bytes % size != 0 {
+            return None;
+        }
+        let consumed = consumed_bytes / size;
+        self.len.checked_sub(consumed)
+    }
+
+    /// Splits the raw slice into a prefix, a middle of aligned elements of type `U`, and a suffix.
+    ///
+    /// This mirrors [slice::align_to]. A NULL raw slice yields three NULL raw slices.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - The same requirements as for [slice::align_to] apply: the elements of the middle raw
+    ///   slice **must** be valid values of type `U`.
+    pub unsafe fn align_to<U>(&self) -> (RawSlice<T>, RawSlice<U>, RawSlice<T>) {
+        match unsafe { self.get() } {
+            Some(slice) => {
+                let (prefix, middle, suffix) = unsafe { slice.align_to::<U>() };
+                unsafe {
+                    (
+                        RawSlice::new(prefix),
+                        RawSlice::new(middle),
+                        RawSlice::new(suffix),
+                    )
+                }
+            }
+            None => (
+                RawSlice::new_nulled(),
+                RawSlice::new_nulled(),
+                RawSlice::new_nulled(),
+            ),
+        }
+    }
+
+    /// Reinterprets the raw slice as a raw slice of the [bytemuck::Pod] type `U`.
+    ///
+    /// Unlike [Self::cast], this function is safe because all bit patterns are valid for
+    /// [bytemuck::Pod] types, and the size and alignment are checked. A NULL raw slice yields
+    /// a NULL raw slice.
+    ///
+    /// # Errors
+    ///
+    /// - [bytemuck::PodCastError::SizeMismatch] if `T` or `U` is a zero-sized type or if the
+    ///   length in bytes overflows a [usize].
+    /// - [bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned] if the pointer is
+    ///   not properly aligned for `U`.
+    /// - [bytemuck::PodCastError::OutputSliceWouldHaveSlop] if the length in bytes is not a
+    ///   multiple of the size of `U`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn cast_pod<U: bytemuck::Pod>(self) -> Result<RawSlice<U>, bytemuck::PodCastError>
+    where
+        T: bytemuck::Pod,
+    {
+        if self.is_null() {
+            return Ok(RawSlice::new_nulled());
+        }
+        let len = check_pod_cast::<T, U>(self.data, self.len)?;
+        Ok(RawSlice {
+            data: self.data as *const U,
+            len,
+        })
+    }
+
+    /// Applies `f` to the length of the raw slice.
+    ///
+    /// The new length is clamped to the current length, so growing the raw slice beyond the
+    /// referenced memory region is deliberately prevented. This has no effect if the pointer
+    /// is null.
+    pub fn map_len<F: FnOnce(usize) -> usize>(&mut self, f: F) {
+        if self.is_null() {
+            return;
+        }
+        self.len = f(self.len).min(self.len);
+    }
+
+    /// Returns whether both raw slices point to the same base address.
+    ///
+    /// In contrast to the [PartialEq] implementation, the length is ignored. Two null raw slices
+    /// are considered equal. This only compares addresses, the data is never dereferenced.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.data, other.data)
+    }
+
+    /// Performs a volatile read of the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds. This is intended for
+    /// raw slices which describe memory-mapped I/O regions, where every access must be performed
+    /// and can not be optimized away or merged by the compiler. See [core::ptr::read_volatile]
+    /// for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn read_volatile(&self, index: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { core::ptr::read_volatile(self.data.add(index)) })
+    }
+
+    /// Copies all elements from the memory referenced by the raw slice into `dst`.
+    ///
+    /// # Errors
+    ///
+    /// - [CopyError::Null] if the pointer is null.
+    /// - [CopyError::LengthMismatch] if the length of `dst` is not equal to the length of the
+    ///   raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - The underlying memory **must not** overlap with `dst`.
+    pub unsafe fn copy_to_slice(&self, dst: &mut [T]) -> Result<(), CopyError>
+    where
+        T: Copy,
+    {
+        if self.is_null() {
+            return Err(CopyError::Null);
+        }
+        if dst.len() != self.len {
+            return Err(CopyError::LengthMismatch);
+        }
+        unsafe { core::ptr::copy_nonoverlapping(self.data, dst.as_mut_ptr(), self.len) };
+        Ok(())
+    }
+
+    /// Returns the index of the first element which matches the predicate `pred`.
+    ///
+    /// Returns [None] if the pointer is null or if no element matches. See
+    /// [Iterator::position] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        unsafe { self.get() }?.iter().position(pred)
+    }
+
+    /// Returns a raw slice with the first `n` elements removed.
+    ///
+    /// Returns [None] if the pointer is null or if `n` is larger than the length. This only
+    /// modifies the pointer and the length, the data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the raw slice describes a single valid allocation, so
+    ///   that offsetting the pointer does not move it past the end of that allocation.
+    pub const unsafe fn strip_prefix_len(&self, n: usize) -> Option<RawSlice<T>> {
+        if self.is_null() || n > self.len {
+            return None;
+        }
+        Some(RawSlice {
+            data: unsafe { self.data.add(n) },
+            len: self.len - n,
+        })
+    }
+
+    /// Returns a raw slice with the last `n` elements removed.
+    ///
+    /// Returns [None] if the pointer is null or if `n` is larger than the length. This only
+    /// modifies the length, the data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid when the returned
+    ///   raw slice is used.
+    pub const unsafe fn strip_suffix_len(&self, n: usize) -> Option<RawSlice<T>> {
+        if self.is_null() || n > self.len {
+            return None;
+        }
+        Some(RawSlice {
+            data: self.data,
+            len: self.len - n,
+        })
+    }
+
+    /// Wraps the raw slice into a [MaxLenSlice] which limits the DMA transfer length to `MAX`.
+    ///
+    /// Returns [None] if the length of the raw slice is larger than `MAX`. A NULL raw slice is
+    /// treated like an empty raw slice.
+    pub const fn with_max_len<const MAX: usize>(self) -> Option<MaxLenSlice<Self, MAX>> {
+        if self.len_or_zero() > MAX {
+            return None;
+        }
+        Some(MaxLenSlice(self))
+    }
+
+    /// Returns the pointer and the length which are used by the [embedded_dma::ReadBuffer]
+    /// implementation.
+    ///
+    /// This allows extracting the DMA buffer parameters explicitly without importing the
+    /// [embedded_dma] traits. The data is never dereferenced.
+    pub const fn as_read_buffer(&self) -> (*const T, usize) {
+        (self.as_ptr(), self.len)
+    }
+
+    /// Returns [None] if the pointer is null and a DMA descriptor tuple otherwise.
+    ///
+    /// The tuple contains the start address and the length in bytes, which is the information
+    /// required for a scatter-gather DMA descriptor entry. Also returns [None] if the calculation
+    /// of the length in bytes overflows. The data is never dereferenced.
+    pub fn dma_descriptor(&self) -> Option<(usize, usize)> {
+        Some((self.data as usize, self.byte_len()?))
+    }
+
+    /// Returns `true` if the pointer is not null and the raw slice is not empty.
+    ///
+    /// This is a convenience predicate which treats a NULL raw slice like an empty raw slice.
+    pub const fn has_data(&self) -> bool {
+        !self.is_null() && self.len > 0
+    }
+
+    /// Returns a raw slice with the data pointer offset by `byte_offset` bytes and the same
+    /// length.
+    ///
+    /// This is useful if the memory is accessed through a different address space alias, for
+    /// example a DMA-visible mirror of an SRAM region. A NULL raw slice yields a NULL raw slice.
+    /// The data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the aliased memory region is valid and equivalent to
+    ///   the original memory region when the returned raw slice is used.
+    /// - The caller **must** ensure that the offset pointer is properly aligned for `T`.
+    pub const unsafe fn with_address_offset(&self, byte_offset: isize) -> RawSlice<T> {
+        if self.is_null() {
+            return RawSlice {
+                data: self.data,
+                len: self.len,
+            };
+        }
+        RawSlice {
+            data: self.data.wrapping_byte_offset(byte_offset),
+            len: self.len,
+        }
+    }
+
+    /// Converts the raw pointer into a reference to an array with `N` elements.
+    ///
+    /// Returns [None] if the pointer is null or if the length is not equal to `N`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
+        if self.data.is_null() || self.len != N {
+            return None;
+        }
+        Some(unsafe { &*(self.data as *const [T; N]) })
+    }
+
+    /// Returns a copy of the raw slice which refers to the same memory.
+    ///
+    /// This is semantically identical to copying the raw slice, but makes the intent of creating a
+    /// second descriptor for the same memory explicit at the call site, for example when handing
+    /// the same buffer to two independent consumers.
+    pub const fn duplicate(&self) -> Self {
+        Self {
+            data: self.data,
+            len: self.len,
+        }
+    }
+
+    /// Returns the stored length, regardless of whether the pointer is null.
+    ///
+    /// In contrast to [Self::len], this does not check the pointer. The stored length of a NULL
+    /// raw slice has no meaning.
+    pub const fn raw_len(&self) -> usize {
+        self.len
+    }
+
+    /// Updates only the stored length and keeps the pointer.
+    ///
+    /// This is useful for bookkeeping where the pointer stays fixed and only the logical length
+    /// changes, for example after the hardware reports a received count.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the pointer refers to at least `len` valid elements.
+    /// - Setting the length of a NULL raw slice creates an invalid state, which must be followed
+    ///   by setting a valid pointer before the raw slice is used.
+    pub const unsafe fn set_raw_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    /// Merges the raw slice with `next` if `next` starts directly after the end of this raw
+    /// slice.
+    ///
+    /// Returns [None] if either pointer is null or if the memory regions are not adjacent. This
+    /// can be used to coalesce adjacent DMA descriptors. This only compares addresses, the data
+    /// is never dereferenced.
+    pub fn concat_adjacent(&self, next: &RawSlice<T>) -> Option<RawSlice<T>> {
+        if self.is_null()
+            || next.is_null()
+            || !core::ptr::eq(self.data.wrapping_add(self.len), next.data)
+        {
+            return None;
+        }
+        Some(RawSlice {
+            data: self.data,
+            len: self.len.checked_add(next.len)?,
+        })
+    }
+
+    /// Reads the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds. In contrast to
+    /// [Self::get_elem], this returns a copy of the element instead of a reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn read_at(&self, index: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { core::ptr::read(self.data.add(index)) })
+    }
+
+    /// Returns a raw slice with the data pointer offset by `count` elements and the length
+    /// adjusted accordingly, so that the end of the raw slice stays the same.
+    ///
+    /// A positive `count` moves the start towards the end like [Self::advance], and a negative
+    /// `count` moves the start backwards and increases the length. Returns [None] if the pointer
+    /// is null, if `count` is larger than the length or if the new length overflows.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within the original
+    ///   allocation. For a negative `count`, the `-count` elements before the start of the raw
+    ///   slice **must** be part of the same allocation.
+    pub const unsafe fn offset(&self, count: isize) -> Option<RawSlice<T>> {
+        if self.is_null() {
+            return None;
+        }
+        let len = if count >= 0 {
+            if count as usize > self.len {
+                return None;
+            }
+            self.len - count as usize
+        } else {
+            match self.len.checked_add(count.unsigned_abs()) {
+                Some(len) => len,
+                None => return None,
+            }
+        };
+        Some(RawSlice {
+            data: unsafe { self.data.offset(count) },
+            len,
+        })
+    }
+}
+
+/// Returns a NULL raw slice created with [Self::new_nulled].
+///
+/// [Self::dangling] can be used to create an empty but non-NULL raw slice instead.
+impl<T> Default for RawSlice<T> {
+    fn default() -> Self {
+        Self::new_nulled()
+    }
+}
+
+/// Compares the data pointer and the length, not the contents.
+impl<T> PartialEq for RawSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<T> Eq for RawSlice<T> {}
+
+/// Hashes the data pointer and the length, not the contents.
+impl<T> core::hash::Hash for RawSlice<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+/// Orders raw slices by the address of the data pointer first and by the length second.
+///
+/// This ordering is based on the identity of the raw slices, not on their contents, and it does
+/// not dereference the data. It is useful to keep a sorted list of buffers for bisecting.
+impl<T> PartialOrd for RawSlice<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders raw slices by the address of the data pointer first and by the length second.
+///
+/// This ordering is based on the identity of the raw slices, not on their contents, and it does
+/// not dereference the data.
+impl<T> Ord for RawSlice<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.data as usize, self.len).cmp(&(other.data as usize, other.len))
+    }
+}
+
+impl RawSlice<u8> {
+    /// Creates a new [RawU8Slice] from the bytes of a string slice.
+    ///
+    /// This is useful to transmit string literals, for example over a serial interface.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the string slice outlives this `RawSlice<u8>`.
+    /// - The original string slice **must not** be mutated while this `RawSlice<u8>` is used.
+    pub const unsafe fn from_str(s: &str) -> Self {
+        Self {
+            data: s.as_ptr(),
+            len: s.len(),
+        }
+    }
+
+    /// Returns [None] if the pointer is null or if `needle` is not found and the index of the
+    /// first occurrence of `needle` otherwise.
+    ///
+    /// This is useful for line based parsing of received data, for example when searching for
+    /// `b'\n'`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn find_byte(&self, needle: u8) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        (0..self.len).find(|&i| unsafe { *self.data.add(i) } == needle)
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+impl RawSlice<u8> {
+    /// Reinterprets the raw byte slice as a raw slice of the [zerocopy::FromBytes] type `U`.
+    ///
+    /// This can be used to get a structured view of a received byte buffer without copying it.
+    /// A NULL raw slice yields a NULL raw slice.
+    ///
+    /// # Errors
+    ///
+    /// - [FromBytesError::Alignment] if the pointer is not properly aligned for `U`.
+    /// - [FromBytesError::Size] if `U` is a zero-sized type or if the length is not a multiple
+    ///   of the size of `U`.
+    pub fn cast_from_bytes<U: zerocopy::FromBytes>(self) -> Result<RawSlice<U>, FromBytesError> {
+        if self.is_null() {
+            return Ok(RawSlice::new_nulled());
+        }
+        Ok(RawSlice {
+            data: self.data as *const U,
+            len: check_bytes_cast::<U>(self.data, self.len)?,
+        })
+    }
+}
+
+impl<T: core::fmt::Debug> RawSlice<T> {
+    /// Returns an adapter which formats the address and the contents of the raw slice with
+    /// [Debug](core::fmt::Debug).
+    ///
+    /// The [Debug](core::fmt::Debug) implementation of the raw slice only formats the address and
+    /// the length. This adapter can be used to show the contents instead, for example inside
+    /// tests where the data is known to be valid. Only the address and the length are formatted
+    /// if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn debug_contents(&self) -> impl core::fmt::Debug + '_ {
+        DebugContents {
+            name: "RawSlice",
+            data: self.data,
+            len: self.len,
+            // Safety: The caller ensures that the underlying memory is still valid.
+            contents: unsafe { self.get() },
+        }
+    }
+}
+
+/// Only the address and the length are formatted, the data is never dereferenced.
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl<T> defmt::Format for RawSlice<T> {
+    fn format(&self, fmt: defmt::Formatter) {
+        if self.is_null() {
+            defmt::write!(fmt, "RawSlice {{ null }}");
+        } else {
+            defmt::write!(
+                fmt,
+                "RawSlice {{ data: {=usize:#x}, len: {} }}",
+                self.data as usize,
+                self.len
+            );
+        }
+    }
+}
+
+pub type RawBufSlice = RawU8Slice;
+pub type RawU8Slice = RawSlice<u8>;
+pub type RawU16Slice = RawSlice<u16>;
+pub type RawU32Slice = RawSlice<u32>;
+pub type RawU64Slice = RawSlice<u64>;
+pub type RawI8Slice = RawSlice<i8>;
+pub type RawI16Slice = RawSlice<i16>;
+pub type RawI32Slice = RawSlice<i32>;
+
+/// This allows using [RawSlice] in DMA APIs which expect a [embedded_dma::ReadBuffer].
+///
+/// However, the user still must ensure that any alignment rules for DMA buffers required by
+/// the hardware are met and than any MPU/MMU configuration necessary is also performed for this
+/// to work properly.
+unsafe impl<T: embedded_dma::Word> embedded_dma::ReadBuffer for RawSlice<T> {
+    type Word = T;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        self.as_read_buffer()
+    }
+}
+
+/// Mutable raw slice which erases the lifetime of a mutably borrowed slice.
+///
+/// Like [RawSlice], this type has the same size as a regular slice reference, and the NULL state
+/// should be used instead of wrapping it inside an [Option].
+///
+/// The [PartialEq], [Ord] and [Hash] implementations use the data pointer and the length, not
+/// the contents.
+#[derive(Debug, Copy, Clone)]
+pub struct RawSliceMut<T> {
+    data: *mut T,
+    len: usize,
+}
+
+/// Safety: This type MUST be used with mutex to ensure concurrent access is valid.
+unsafe impl<T: Send> Send for RawSliceMut<T> {}
+
+impl<T> RawSliceMut<T> {
+    /// Creates a new `RawSlice<T>` from a slice reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
+    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
+    #[allow(dead_code)]
+    pub const unsafe fn new(data: &mut [T]) -> Self {
+        Self {
+            data: data.as_mut_ptr(),
+            len: data.len(),
+        }
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a slice reference which only exposes the first `len`
+    /// elements.
+    ///
+    /// The stored length is clamped to the length of `data`, which prevents exposing elements past
+    /// the end of the original slice. This is useful for pre-sized buffers where the logical
+    /// payload is shorter than the allocation.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSliceMut<T>`.
+    /// - The original slice **must not** be accessed through other pointers while this
+    ///   `RawSliceMut<T>` is used.
+    pub const unsafe fn new_with_len(data: &mut [T], len: usize) -> Self {
+        let max_len = data.len();
+        Self {
+            data: data.as_mut_ptr(),
+            len: if len < max_len { len } else { max_len },
+        }
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a slice reference after checking that the slice lies
+    /// entirely within a permitted memory region.
+    ///
+    /// The memory region is described by the start address `region_start` and the length in
+    /// bytes `region_len`. This can be used to verify that a buffer is located inside a region
+    /// which is accessible by a DMA peripheral, for example one configured with an MPU.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSliceMut<T>`.
+    /// - The original slice **must not** be accessed through other pointers while this
+    ///   `RawSliceMut<T>` is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [RegionError] if the byte range of the slice is not contained in the region.
+    pub unsafe fn new_within(
+        data: &mut [T],
+        region_start: *const u8,
+        region_len: usize,
+    ) -> Result<Self, RegionError> {
+        let start = data.as_ptr() as usize;
+        let region_start = region_start as usize;
+        let contained = match (
+            start.checked_add(core::mem::size_of_val(data)),
+            region_start.checked_add(region_len),
+        ) {
+            (Some(end), Some(region_end)) => start >= region_start && end <= region_end,
+            _ => false,
+        };
+        if !contained {
+            return Err(RegionError);
+        }
+        Ok(unsafe { Self::new(data) })
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a raw pointer and a length.
+    ///
+    /// This is equivalent to [Self::new], but does not require an intermediate slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that `data` points to `len` valid and initialized elements
+    ///   which outlive this `RawSliceMut<T>`.
+    /// - The pointed-to data **must not** be accessed through other pointers while this
+    ///   `RawSliceMut<T>` is used.
+    ///
+    /// If `data` is NULL, the raw slice is treated as NULL independently of `len`.
+    pub const unsafe fn from_raw_parts(data: *mut T, len: usize) -> Self {
+        Self { data, len }
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a raw pointer and a length after checking the alignment.
+    ///
+    /// This is equivalent to [Self::from_raw_parts], but returns [AlignError] if `data` is not
+    /// aligned to the alignment of `T`. A NULL pointer is always accepted.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for [Self::from_raw_parts] apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [AlignError] if `data` is not aligned to [core::mem::align_of] `T`.
+    pub unsafe fn from_raw_parts_aligned(data: *mut T, len: usize) -> Result<Self, AlignError> {
+        if !data.is_null() && !data.is_aligned() {
+            return Err(AlignError);
+        }
+        Ok(Self { data, len })
+    }
+
+    /// Creates a new `RawSliceMut<T>` with a length of 1 from a mutable reference to a single
+    /// element.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the element outlives this `RawSliceMut<T>`.
+    /// - The original element **must not** be accessed while this `RawSliceMut<T>` is used.
+    pub const unsafe fn from_mut(value: &mut T) -> Self {
+        Self {
+            data: value as *mut T,
+            len: 1,
+        }
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a mutable array reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the array outlives this `RawSliceMut<T>`.
+    /// - The original array **must not** be accessed while this `RawSliceMut<T>` is used.
+    pub const unsafe fn from_array_mut<const N: usize>(arr: &mut [T; N]) -> Self {
+        Self {
+            data: arr.as_mut_ptr(),
+            len: N,
+        }
+    }
+
+    /// Creates a new `RawSliceMut<T>` from a slice of possibly uninitialized elements.
+    ///
+    /// This is useful for DMA RX buffers which are only initialized by the DMA transfer.
+    /// [Self::assume_init_get_mut] can be used to access the data once it was initialized.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSliceMut<T>`.
+    /// - The original slice **must not** be accessed while this `RawSliceMut<T>` is used.
+    /// - The caller **must not** reconstruct a slice with [Self::get] or [Self::get_mut] or any
+    ///   other function reading the data before all elements were initialized.
+    pub const unsafe fn from_uninit(data: &mut [core::mem::MaybeUninit<T>]) -> Self {
+        Self {
+            data: data.as_mut_ptr() as *mut T,
+            len: data.len(),
+        }
+    }
+
+    /// Creates an empty `RawSlice<T>`, equivalent to a null pointer with zero length.
+    pub const fn new_nulled() -> Self {
+        Self {
+            data: core::ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty `RawSliceMut<T>` with a dangling, non-NULL pointer.
+    ///
+    /// This mirrors how an empty slice reference is represented: [Self::is_null] returns
+    /// `false`, [Self::is_empty] returns `Some(true)` and [Self::get] returns an empty slice
+    /// instead of [None].
+    pub const fn dangling() -> Self {
+        Self {
+            data: NonNull::<T>::dangling().as_ptr(),
+            len: 0,
+        }
+    }
+
+    /// Updates the raw pointer and length to point to a new slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSlice<T>`.
+    /// - The original slice **must not** be mutated while this `RawSlice<T>` is used.
+    pub const unsafe fn set(&mut self, data: &mut [T]) {
+        self.data = data.as_mut_ptr();
+        self.len = data.len();
+    }
+
+    /// Converts the raw pointer into a slice.
+    ///
+    /// Returns [None] if the pointer is null. A non-NULL raw slice with a length of 0, for
+    /// example one created with [Self::dangling], always returns an empty slice. This allows
+    /// distinguishing NULL, empty and non-empty raw slices.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get<'slice>(&self) -> Option<&'slice [T]> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts(self.data, self.len) })
+    }
+
+    /// Converts the raw pointer into a slice.
+    ///
+    /// This is equivalent to [Self::get], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn try_get<'slice>(&self) -> Result<&'slice [T], NullSliceError> {
+        match unsafe { self.get() } {
+            Some(slice) => Ok(slice),
+            None => Err(NullSliceError),
+        }
+    }
+
+    /// Converts the raw pointer into a slice, returning an empty slice if the pointer is null.
+    ///
+    /// This intentionally treats a NULL raw slice like an empty raw slice, which is useful for
+    /// callers which do not care about the distinction, for example in test code.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_or_empty<'slice>(&self) -> &'slice [T] {
+        match unsafe { self.get() } {
+            Some(slice) => slice,
+            None => &[],
+        }
+    }
+
+    /// Converts the raw pointer into a mutable slice.
+    ///
+    /// Returns [None] if the pointer is null. A non-NULL raw slice with a length of 0, for
+    /// example one created with [Self::dangling], always returns an empty slice. This allows
+    /// distinguishing NULL, empty and non-empty raw slices.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_mut<'slice>(&mut self) -> Option<&'slice mut [T]> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts_mut(self.data, self.len) })
+    }
+
+    /// Converts the raw pointer into a mutable slice.
+    ///
+    /// This is equivalent to [Self::get_mut], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn try_get_mut<'slice>(&mut self) -> Result<&'slice mut [T], NullSliceError> {
+        match unsafe { self.get_mut() } {
+            Some(slice) => Ok(slice),
+            None => Err(NullSliceError),
+        }
+    }
+
+    /// Converts the raw pointer into a mutable slice, returning an empty slice if the pointer is
+    /// null.
+    ///
+    /// This intentionally treats a NULL raw slice like an empty raw slice, which is useful for
+    /// callers which do not care about the distinction, for example in test code.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_mut_or_empty<'slice>(&mut self) -> &'slice mut [T] {
+        match unsafe { self.get_mut() } {
+            Some(slice) => slice,
+            None => &mut [],
+        }
+    }
+
+    pub const fn set_null(&mut self) {
+        self.data = core::ptr::null_mut();
+        self.len = 0;
+    }
+
+    /// Takes the raw slice out, leaving a NULL raw slice in its place.
+    ///
+    /// This is similar to [Option::take].
+    pub const fn take(&mut self) -> RawSliceMut<T> {
+        let taken = RawSliceMut {
+            data: self.data,
+            len: self.len,
+        };
+        self.set_null();
+        taken
+    }
+
+    /// Updates the raw slice to point to a new slice and returns the previous raw slice.
+    ///
+    /// This is similar to [core::mem::replace].
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawSliceMut<T>`.
+    /// - The original slice **must not** be accessed through other pointers while this
+    ///   `RawSliceMut<T>` is used.
+    pub const unsafe fn replace(&mut self, data: &mut [T]) -> RawSliceMut<T> {
+        let previous = self.take();
+        unsafe { self.set(data) };
+        previous
+    }
+
+    pub const fn is_null(&self) -> bool {
+        self.data.is_null()
+    }
+
+    /// Returns [None] if the pointer is null and whether [Self::len] is 0 otherwise.
+    pub const fn is_empty(&self) -> Option<bool> {
+        if self.is_null() {
+            return None;
+        }
+        Some(self.len == 0)
+    }
+
+    /// Returns [None] if the pointer is null and the length of the raw slice otherwise.
+    pub const fn len(&self) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        Some(self.len)
+    }
+
+    /// Returns the length of the raw slice.
+    ///
+    /// This is equivalent to [Self::len], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    pub const fn try_len(&self) -> Result<usize, NullSliceError> {
+        if self.is_null() {
+            return Err(NullSliceError);
+        }
+        Ok(self.len)
+    }
+
+    /// Returns whether [Self::len] is 0.
+    ///
+    /// This is equivalent to [Self::is_empty], but returns a [Result] which allows using the `?`
+    /// operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [NullSliceError] if the pointer is null.
+    pub const fn try_is_empty(&self) -> Result<bool, NullSliceError> {
+        if self.is_null() {
+            return Err(NullSliceError);
+        }
+        Ok(self.len == 0)
+    }
+
+    /// Consumes the raw slice and returns the raw pointer and the length.
+    ///
+    /// Returns a NULL pointer and a length of 0 if the raw slice is NULL. The returned parts can
+    /// be converted back with [Self::from_raw_parts].
+    pub const fn into_raw_parts(self) -> (*mut T, usize) {
+        if self.is_null() {
+            return (core::ptr::null_mut(), 0);
+        }
+        (self.data, self.len)
+    }
+
+    /// Returns the raw data pointer, which might be NULL.
+    pub const fn as_ptr(&self) -> *const T {
+        self.data
+    }
+
+    /// Returns the raw mutable data pointer, which might be NULL.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.data
+    }
+
+    /// Creates a read-only [RawSlice] pointing to the same data.
+    ///
+    /// A NULL raw slice yields a NULL [RawSlice].
+    pub const fn downgrade(&self) -> RawSlice<T> {
+        RawSlice {
+            data: self.data as *const T,
+            len: self.len,
+        }
+    }
+
+    /// Returns the raw data pointer as a [NonNull] pointer, or [None] if it is NULL.
+    pub const fn as_non_null(&self) -> Option<NonNull<T>> {
+        NonNull::new(self.data)
+    }
+
+    /// Returns the raw slice as a [NonNull] slice pointer which contains both the data pointer
+    /// and the length, or [None] if it is NULL.
+    pub const fn as_non_null_slice(&self) -> Option<NonNull<[T]>> {
+        match self.as_non_null() {
+            Some(data) => Some(NonNull::slice_from_raw_parts(data, self.len)),
+            None => None,
+        }
+    }
+
+    /// Converts the raw pointer into a slice without checking whether the pointer is NULL.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the raw slice is not NULL. Calling this function on
+    ///   a NULL raw slice is UB.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_unchecked<'slice>(&self) -> &'slice [T] {
+        unsafe { core::slice::from_raw_parts(self.data, self.len) }
+    }
+
+    /// Converts the raw pointer into a mutable slice without checking whether the pointer is
+    /// NULL.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the raw slice is not NULL. Calling this function on
+    ///   a NULL raw slice is UB.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_unchecked_mut<'slice>(&mut self) -> &'slice mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.data, self.len) }
+    }
+
+    /// Returns a reference to the element at position `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_elem<'slice>(&self, index: usize) -> Option<&'slice T> {
+        if self.data.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { &*self.data.add(index) })
+    }
+
+    /// Returns a mutable reference to the element at position `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get_elem_mut<'slice>(&mut self, index: usize) -> Option<&'slice mut T> {
+        if self.data.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { &mut *self.data.add(index) })
+    }
+
+    /// Divides the mutable raw slice into two non-overlapping mutable raw slices at the index
+    /// `mid`.
+    ///
+    /// The first raw slice contains the elements `[0, mid)` and the second raw slice contains
+    /// the elements `[mid, len)`. Returns [None] if the pointer is null or if `mid > len`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub const unsafe fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Option<(RawSliceMut<T>, RawSliceMut<T>)> {
+        if self.data.is_null() || mid > self.len {
+            return None;
+        }
+        Some((
+            RawSliceMut {
+                data: self.data,
+                len: mid,
+            },
+            RawSliceMut {
+                data: unsafe { self.data.add(mid) },
+                len: self.len - mid,
+            },
+        ))
+    }
+
+    /// Divides the mutable raw slice into two non-overlapping mutable raw slices at the index
+    /// `mid` without any checks.
+    ///
+    /// This is the unchecked variant of [Self::split_at_mut].
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the pointer is not null and that `mid <= len`.
+    ///   Violating this results in UB.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub const unsafe fn split_at_mut_unchecked(
+        &mut self,
+        mid: usize,
+    ) -> (RawSliceMut<T>, RawSliceMut<T>) {
+        (
+            RawSliceMut {
+                data: self.data,
+                len: mid,
+            },
+            RawSliceMut {
+                data: unsafe { self.data.add(mid) },
+                len: unsafe { self.len.unchecked_sub(mid) },
+            },
+        )
+    }
+
+    /// Returns a mutable raw slice containing the elements `[start, end)`.
+    ///
+    /// Returns [None] if the pointer is null, if `start > end` or if `end > len`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub const unsafe fn subslice_mut(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Option<RawSliceMut<T>> {
+        if self.data.is_null() || start > end || end > self.len {
+            return None;
+        }
+        Some(RawSliceMut {
+            data: unsafe { self.data.add(start) },
+            len: end - start,
+        })
+    }
+
+    /// Compares the contents of the raw slice with a regular slice.
+    ///
+    /// Returns `false` if the pointer is null. See [RawSlice::eq_slice] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        match unsafe { self.get() } {
+            Some(slice) => slice == other,
+            None => false,
+        }
+    }
+
+    /// Returns [None] if the pointer is null and the length of the raw slice in bytes otherwise.
+    ///
+    /// Also returns [None] if the calculation of the length in bytes overflows.
+    pub const fn byte_len(&self) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        self.len.checked_mul(core::mem::size_of::<T>())
+    }
+
+    /// Returns [None] if the pointer is null and whether the pointer is aligned to `align`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    pub fn is_aligned_to(&self, align: usize) -> Option<bool> {
+        assert!(align.is_power_of_two(), "alignment is not a power of two");
+        if self.is_null() {
+            return None;
+        }
+        Some(self.data as usize % align == 0)
+    }
+
+    /// Returns [None] if the pointer is null and the alignment of the pointer otherwise.
+    ///
+    /// The alignment is the largest power of two which divides the address. This can be used to
+    /// verify the alignment before calling [Self::cast] or to diagnose misaligned DMA buffers.
+    pub fn alignment(&self) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        Some(1 << (self.data as usize).trailing_zeros())
+    }
+
+    /// Reinterprets the raw slice as a raw slice of the element type `U`.
+    ///
+    /// See [RawSlice::cast] for more details. A NULL raw slice is returned if the length in
+    /// bytes overflows a [usize].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` is a zero-sized type.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the pointer is properly aligned for `U`, which can be
+    ///   checked with [Self::alignment] or [Self::is_aligned_to].
+    /// - The caller **must** ensure that the underlying memory is a valid `[U]` and that any
+    ///   value of `U` written to it is also valid for `T`.
+    pub const unsafe fn cast<U>(self) -> RawSliceMut<U> {
+        assert!(core::mem::size_of::<U>() != 0, "cast to zero-sized type");
+        match self.len.checked_mul(core::mem::size_of::<T>()) {
+            Some(byte_len) => RawSliceMut {
+                data: self.data as *mut U,
+                len: byte_len / core::mem::size_of::<U>(),
+            },
+            None => RawSliceMut::new_nulled(),
+        }
+    }
+
+    /// Returns a mutable raw byte slice covering the same memory region.
+    ///
+    /// A NULL raw slice yields a NULL raw byte slice, and a NULL raw byte slice is also returned
+    /// if the length in bytes overflows a [usize].
+    pub const fn as_bytes_mut(&mut self) -> RawU8SliceMut {
+        match self.len.checked_mul(core::mem::size_of::<T>()) {
+            Some(len) => RawSliceMut {
+                data: self.data as *mut u8,
+                len,
+            },
+            None => RawSliceMut::new_nulled(),
+        }
+    }
+
+    /// Shortens the raw slice to `new_len` elements.
+    ///
+    /// This has no effect if `new_len` is greater than or equal to the current length, or if the
+    /// pointer is null.
+    ///
+    /// In contrast to [Self::set], which replaces both the pointer and the length, this only
+    /// updates the length and can never grow the raw slice past its current length. This makes it
+    /// suitable for exposing a shorter logical length of a fixed allocation. Use
+    /// [Self::new_with_len] on the original allocation to expose a longer length again.
+    pub const fn truncate(&mut self, new_len: usize) {
+        if self.is_null() {
+            return;
+        }
+        if new_len < self.len {
+            self.len = new_len;
+        }
+    }
+
+    /// Advances the start of the raw slice by `n` elements and reduces the length accordingly.
+    ///
+    /// Advancing by `n >= len` yields an empty raw slice pointing just past the end of the
+    /// original raw slice. This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within or one element past
+    ///   the end of the original allocation.
+    pub const unsafe fn advance(&mut self, n: usize) {
+        if self.is_null() {
+            return;
+        }
+        let n = if n > self.len { self.len } else { n };
+        self.data = unsafe { self.data.add(n) };
+        self.len -= n;
+    }
+
+    /// Removes `front` elements from the start and `back` elements from the end of the raw
+    /// slice.
+    ///
+    /// This is equivalent to calling [Self::advance] with `front` and [Self::truncate] with the
+    /// remaining length minus `back`. If `front` and `back` exceed the length, the raw slice
+    /// becomes empty. This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within or one element past
+    ///   the end of the original allocation.
+    pub const unsafe fn shrink(&mut self, front: usize, back: usize) {
+        if self.is_null() {
+            return;
+        }
+        unsafe { self.advance(front) };
+        self.len = self.len.saturating_sub(back);
+    }
+
+    /// Copies all elements from `src` into the memory referenced by the raw slice.
+    ///
+    /// # Errors
+    ///
+    /// - [CopyError::Null] if the pointer is null.
+    /// - [CopyError::LengthMismatch] if the length of `src` is not equal to the length of the
+    ///   raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - The underlying memory **must not** overlap with `src`.
+    pub unsafe fn copy_from_slice(&mut self, src: &[T]) -> Result<(), CopyError>
+    where
+        T: Copy,
+    {
+        if self.is_null() {
+            return Err(CopyError::Null);
+        }
+        if src.len() != self.len {
+            return Err(CopyError::LengthMismatch);
+        }
+        unsafe { core::ptr::copy_nonoverlapping(src.as_ptr(), self.data, self.len) };
+        Ok(())
+    }
+
+    /// Fills the memory referenced by the raw slice with `value`.
+    ///
+    /// This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        if let Some(slice) = unsafe { self.get_mut() } {
+            slice.fill(value);
+        }
+    }
+
+    /// Returns a reference to the first element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn first<'slice>(&self) -> Option<&'slice T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &*self.data })
+    }
+
+    /// Returns a mutable reference to the first element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn first_mut<'slice>(&mut self) -> Option<&'slice mut T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &mut *self.data })
+    }
+
+    /// Returns a reference to the last element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn last<'slice>(&self) -> Option<&'slice T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &*self.data.add(self.len - 1) })
+    }
+
+    /// Returns a mutable reference to the last element.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn last_mut<'slice>(&mut self) -> Option<&'slice mut T> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some(unsafe { &mut *self.data.add(self.len - 1) })
+    }
+
+    /// Returns an iterator over the elements of the raw slice.
+    ///
+    /// The iterator yields nothing if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime of
+    ///   the iterator.
+    /// - Using the iterator after the original slice is dropped results in UB.
+    pub unsafe fn iter(&self) -> RawSliceIter<'_, T> {
+        RawSliceIter::new(self.data, self.len)
+    }
+
+    /// Returns a [ValidRawSlice] which allows safe iteration for the lifetime `'slice`.
+    ///
+    /// A NULL raw slice yields a [ValidRawSlice] without any elements.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime
+    ///   `'slice`.
+    /// - The underlying memory **must not** be mutated during the lifetime `'slice`.
+    pub const unsafe fn assume_valid<'slice>(&self) -> ValidRawSlice<'slice, T> {
+        ValidRawSlice {
+            data: self.data,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns [None] if the pointer is null and a borrow-checked [RawSliceView] with the
+    /// lifetime `'slice` otherwise.
+    ///
+    /// This is the single unsafe step required to access the raw slice, all accesses through the
+    /// view are safe.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory remains valid for the lifetime
+    ///   `'slice`.
+    /// - The underlying memory **must not** be mutated during the lifetime `'slice`.
+    pub const unsafe fn view<'slice>(&self) -> Option<RawSliceView<'slice, T>> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(RawSliceView(unsafe {
+            core::slice::from_raw_parts(self.data, self.len)
+        }))
+    }
+
+    /// Returns the length of the raw slice, treating a NULL raw slice as empty.
+    pub const fn len_or_zero(&self) -> usize {
+        if self.is_null() {
+            return 0;
+        }
+        self.len
+    }
+
+    /// Returns whether the raw slice is empty, treating a NULL raw slice as empty.
+    pub const fn is_empty_or_true(&self) -> bool {
+        self.len_or_zero() == 0
+    }
+
+    /// Returns a mutable raw slice containing the elements within `range`.
+    ///
+    /// See [RawSlice::get_range] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    pub unsafe fn get_range_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Option<RawSliceMut<T>> {
+        let (start, end) = range_to_start_end(range, self.len)?;
+        unsafe { self.subslice_mut(start, end) }
+    }
+
+    /// Returns the first element and a mutable raw slice containing the remaining elements.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn split_first_mut<'slice>(
+        &mut self,
+    ) -> Option<(&'slice mut T, RawSliceMut<T>)> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some((
+            unsafe { &mut *self.data },
+            RawSliceMut {
+                data: unsafe { self.data.add(1) },
+                len: self.len - 1,
+            },
+        ))
+    }
+
+    /// Returns the last element and a mutable raw slice containing the remaining elements.
+    ///
+    /// Returns [None] if the pointer is null or if the raw slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn split_last_mut<'slice>(
+        &mut self,
+    ) -> Option<(&'slice mut T, RawSliceMut<T>)> {
+        if self.is_null() || self.len == 0 {
+            return None;
+        }
+        Some((
+            unsafe { &mut *self.data.add(self.len - 1) },
+            RawSliceMut {
+                data: self.data,
+                len: self.len - 1,
+            },
+        ))
+    }
+
+    /// Returns [None] if the pointer is null and whether `ptr` points to an element of the raw
+    /// slice otherwise.
+    ///
+    /// This only compares addresses, the data is never dereferenced.
+    pub fn contains_ptr(&self, ptr: *const T) -> Option<bool> {
+        if self.is_null() {
+            return None;
+        }
+        let start = self.as_ptr();
+        Some(ptr >= start && ptr < start.wrapping_add(self.len))
+    }
+
+    /// Returns the number of elements remaining from `current` to the end of the raw slice.
+    ///
+    /// Returns [None] if the pointer is null, if `current` is not within the range
+    /// `[data, data + len]` or if `current` does not point to an element boundary. This is useful
+    /// to calculate the remaining elements from a "current address" register of a DMA peripheral.
+    /// The data is never dereferenced.
+    pub fn remaining_from(&self, current: *const T) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        let start = self.as_ptr() as usize;
+        let current = current as usize;
+        let consumed_bytes = current.checked_sub(start)?;
+        let size = core::mem::size_of::<T>().max(1);
+        if consumed_bytes % size != 0 {
+            return None;
+        }
+        let consumed = consumed_bytes / size;
+        self.len.checked_sub(consumed)
+    }
+
+    /// Converts the raw pointer into a mutable slice, assuming that all elements were
+    /// initialized.
+    ///
+    /// This is the counterpart to [Self::from_uninit]. Returns [None] if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that all elements were initialized, for example by
+    ///   a completed DMA transfer.
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn assume_init_get_mut<'slice>(&mut self) -> Option<&'slice mut [T]> {
+        unsafe { self.get_mut() }
+    }
+
+    /// Swaps the contents of this raw slice with the contents of `other`.
+    ///
+    /// # Errors
+    ///
+    /// - [SwapError::Null] if one of the pointers is null.
+    /// - [SwapError::LengthMismatch] if the lengths of the raw slices are not equal.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory of both raw slices is still
+    ///   valid.
+    /// - The memory regions of both raw slices **must not** overlap.
+    pub unsafe fn swap_contents(&mut self, other: &mut RawSliceMut<T>) -> Result<(), SwapError>
+    where
+        T: Copy,
+    {
+        if self.is_null() || other.is_null() {
+            return Err(SwapError::Null);
+        }
+        if self.len != other.len {
+            return Err(SwapError::LengthMismatch);
+        }
+        unsafe { core::ptr::swap_nonoverlapping(self.data, other.data, self.len) };
+        Ok(())
+    }
+
+    /// Reinterprets the raw slice as a raw slice of the [bytemuck::Pod] type `U`.
+    ///
+    /// See [RawSlice::cast_pod] for more details.
+    ///
+    /// # Errors
+    ///
+    /// - [bytemuck::PodCastError::SizeMismatch] if `T` or `U` is a zero-sized type or if the
+    ///   length in bytes overflows a [usize].
+    /// - [bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned] if the pointer is
+    ///   not properly aligned for `U`.
+    /// - [bytemuck::PodCastError::OutputSliceWouldHaveSlop] if the length in bytes is not a
+    ///   multiple of the size of `U`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn cast_pod<U: bytemuck::Pod>(self) -> Result<RawSliceMut<U>, bytemuck::PodCastError>
+    where
+        T: bytemuck::Pod,
+    {
+        if self.is_null() {
+            return Ok(RawSliceMut::new_nulled());
+        }
+        let len = check_pod_cast::<T, U>(self.data, self.len)?;
+        Ok(RawSliceMut {
+            data: self.data as *mut U,
+            len,
+        })
+    }
+
+    /// Applies `f` to the length of the raw slice.
+    ///
+    /// The new length is clamped to the current length, so growing the raw slice beyond the
+    /// referenced memory region is deliberately prevented. This has no effect if the pointer
+    /// is null.
+    pub fn map_len<F: FnOnce(usize) -> usize>(&mut self, f: F) {
+        if self.is_null() {
+            return;
+        }
+        self.len = f(self.len).min(self.len);
+    }
+
+    /// Returns whether both raw slices point to the same base address.
+    ///
+    /// In contrast to the [PartialEq] implementation, the length is ignored. Two null raw slices
+    /// are considered equal. This only compares addresses, the data is never dereferenced.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.data, other.data)
+    }
+
+    /// Returns [None] if either pointer is null and whether the memory regions of both raw slices
+    /// overlap otherwise. Empty raw slices never overlap.
+    ///
+    /// This can be used to verify that two buffers handed to different peripherals do not alias.
+    /// This only compares addresses, the data is never dereferenced.
+    pub fn overlaps(&self, other: &RawSliceMut<T>) -> Option<bool> {
+        if self.is_null() || other.is_null() {
+            return None;
+        }
+        let size = core::mem::size_of::<T>();
+        let self_start = self.data as usize;
+        let other_start = other.data as usize;
+        let self_size = self.len.wrapping_mul(size);
+        let other_size = other.len.wrapping_mul(size);
+        // Empty regions can not overlap with anything.
+        if self_size == 0 || other_size == 0 {
+            return Some(false);
+        }
+        Some(
+            self_start < other_start.wrapping_add(other_size)
+                && other_start < self_start.wrapping_add(self_size),
+        )
+    }
+
+    /// Rotates the memory referenced by the raw slice in-place such that the first `mid`
+    /// elements move to the end.
+    ///
+    /// This has no effect if the pointer is null or if the raw slice is empty. `mid` is taken
+    /// modulo the length of the raw slice. See [slice::rotate_left] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn rotate_left(&mut self, mid: usize) {
+        if let Some(slice) = unsafe { self.get_mut() } {
+            if !slice.is_empty() {
+                let len = slice.len();
+                slice.rotate_left(mid % len);
+            }
+        }
+    }
+
+    /// Rotates the memory referenced by the raw slice in-place such that the last `k` elements
+    /// move to the front.
+    ///
+    /// This has no effect if the pointer is null or if the raw slice is empty. `k` is taken
+    /// modulo the length of the raw slice. See [slice::rotate_right] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn rotate_right(&mut self, k: usize) {
+        if let Some(slice) = unsafe { self.get_mut() } {
+            if !slice.is_empty() {
+                let len = slice.len();
+                slice.rotate_right(k % len);
+            }
+        }
+    }
+
+    /// Performs a volatile read of the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds. This is intended for
+    /// raw slices which describe memory-mapped I/O regions, where every access must be performed
+    /// and can not be optimized away or merged by the compiler. See [core::ptr::read_volatile]
+    /// for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn read_volatile(&self, index: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { core::ptr::read_volatile(self.data.add(index)) })
+    }
+
+    /// Performs a volatile write of `value` to the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds and `Some(())`
+    /// otherwise. This is intended for raw slices which describe memory-mapped I/O regions, where
+    /// every access must be performed and can not be optimized away or merged by the compiler. See
+    /// [core::ptr::write_volatile] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn write_volatile(&mut self, index: usize, value: T) -> Option<()>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        unsafe { core::ptr::write_volatile(self.data.add(index), value) };
+        Some(())
+    }
+
+    /// Copies all elements from the memory referenced by the raw slice into `dst`.
+    ///
+    /// # Errors
+    ///
+    /// - [CopyError::Null] if the pointer is null.
+    /// - [CopyError::LengthMismatch] if the length of `dst` is not equal to the length of the
+    ///   raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - The underlying memory **must not** overlap with `dst`.
+    pub unsafe fn copy_to_slice(&self, dst: &mut [T]) -> Result<(), CopyError>
+    where
+        T: Copy,
+    {
+        if self.is_null() {
+            return Err(CopyError::Null);
+        }
+        if dst.len() != self.len {
+            return Err(CopyError::LengthMismatch);
+        }
+        unsafe { core::ptr::copy_nonoverlapping(self.data, dst.as_mut_ptr(), self.len) };
+        Ok(())
+    }
+
+    /// Returns the index of the first element which matches the predicate `pred`.
+    ///
+    /// Returns [None] if the pointer is null or if no element matches. See
+    /// [Iterator::position] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        unsafe { self.get() }?.iter().position(pred)
+    }
+
+    /// Returns a raw slice with the first `n` elements removed.
+    ///
+    /// Returns [None] if the pointer is null or if `n` is larger than the length. This only
+    /// modifies the pointer and the length, the data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the raw slice describes a single valid allocation, so
+    ///   that offsetting the pointer does not move it past the end of that allocation.
+    pub const unsafe fn strip_prefix_len(&mut self, n: usize) -> Option<RawSliceMut<T>> {
+        if self.is_null() || n > self.len {
+            return None;
+        }
+        Some(RawSliceMut {
+            data: unsafe { self.data.add(n) },
+            len: self.len - n,
+        })
+    }
+
+    /// Returns a raw slice with the last `n` elements removed.
+    ///
+    /// Returns [None] if the pointer is null or if `n` is larger than the length. This only
+    /// modifies the length, the data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid when the returned
+    ///   raw slice is used.
+    pub const unsafe fn strip_suffix_len(&mut self, n: usize) -> Option<RawSliceMut<T>> {
+        if self.is_null() || n > self.len {
+            return None;
+        }
+        Some(RawSliceMut {
+            data: self.data,
+            len: self.len - n,
+        })
+    }
+
+    /// Converts the raw pointer into a slice of [Cell](core::cell::Cell)s.
+    ///
+    /// Returns [None] if the pointer is null. [Cell](core::cell::Cell) has the same memory
+    /// layout as `T`, so this allows shared mutation of individual elements without creating
+    /// aliasing mutable references, for example when multiple cooperative tasks write to
+    /// disjoint elements of a shared buffer.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    /// - The elements **must not** be accessed through other pointers while the returned slice
+    ///   is used. In particular, this is only sound if no interrupt or other context with
+    ///   preemptive priority writes to the same memory.
+    pub const unsafe fn as_cells<'slice>(&self) -> Option<&'slice [core::cell::Cell<T>]> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(unsafe {
+            core::slice::from_raw_parts(self.data as *const core::cell::Cell<T>, self.len)
+        })
+    }
+
+    /// Returns a new raw slice with the same pointer and length for temporary exclusive use.
+    ///
+    /// This is the raw slice analogue of reborrowing a mutable reference with `&mut *x`. It is
+    /// equivalent to copying the raw slice, but documents the intent at the call site.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the original raw slice is not used to access the
+    ///   underlying memory while the returned raw slice is used.
+    pub const unsafe fn reborrow(&mut self) -> RawSliceMut<T> {
+        RawSliceMut {
+            data: self.data,
+            len: self.len,
+        }
+    }
+
+    /// Wraps the raw slice into a [MaxLenSlice] which limits the DMA transfer length to `MAX`.
+    ///
+    /// Returns [None] if the length of the raw slice is larger than `MAX`. A NULL raw slice is
+    /// treated like an empty raw slice.
+    pub const fn with_max_len<const MAX: usize>(self) -> Option<MaxLenSlice<Self, MAX>> {
+        if self.len_or_zero() > MAX {
+            return None;
+        }
+        Some(MaxLenSlice(self))
+    }
+
+    /// Returns the pointer and the length which are used by the [embedded_dma::ReadBuffer]
+    /// implementation.
+    ///
+    /// This allows extracting the DMA buffer parameters explicitly without importing the
+    /// [embedded_dma] traits. The data is never dereferenced.
+    pub const fn as_read_buffer(&self) -> (*const T, usize) {
+        (self.as_ptr(), self.len)
+    }
+
+    /// Returns the pointer and the length which are used by the [embedded_dma::WriteBuffer]
+    /// implementation.
+    ///
+    /// This allows extracting the DMA buffer parameters explicitly without importing the
+    /// [embedded_dma] traits. The data is never dereferenced.
+    pub const fn as_write_buffer(&mut self) -> (*mut T, usize) {
+        (self.data, self.len)
+    }
+
+    /// Sets all bytes of the memory referenced by the raw slice to zero.
+    ///
+    /// This has no effect if the pointer is null. In contrast to [Self::fill], this operates on
+    /// the byte level, which can be cheaper for large buffers. See [core::ptr::write_bytes] for
+    /// more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    /// - The all-zero byte pattern **must** be a valid value of `T`. This is the case for all
+    ///   integer types, but not for references or [core::num::NonZero] types.
+    pub unsafe fn zeroed(&mut self)
+    where
+        T: Copy,
+    {
+        if self.is_null() {
+            return;
+        }
+        unsafe { core::ptr::write_bytes(self.data, 0, self.len) };
+    }
+
+    /// Returns [None] if the pointer is null and a DMA descriptor tuple otherwise.
+    ///
+    /// The tuple contains the start address and the length in bytes, which is the information
+    /// required for a scatter-gather DMA descriptor entry. Also returns [None] if the calculation
+    /// of the length in bytes overflows. The data is never dereferenced.
+    pub fn dma_descriptor(&self) -> Option<(usize, usize)> {
+        Some((self.data as usize, self.byte_len()?))
+    }
+
+    /// Copies the elements `[src_start, src_end)` to the position starting at `dest` within the
+    /// raw slice.
+    ///
+    /// The source and destination ranges may overlap. See [slice::copy_within] for more details.
+    ///
+    /// # Errors
+    ///
+    /// - [CopyError::Null] if the pointer is null.
+    /// - [CopyError::OutOfBounds] if `src_start > src_end`, if `src_end > len` or if the
+    ///   destination range exceeds the length of the raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn copy_within(
+        &mut self,
+        src_start: usize,
+        src_end: usize,
+        dest: usize,
+    ) -> Result<(), CopyError>
+    where
+        T: Copy,
+    {
+        if self.is_null() {
+            return Err(CopyError::Null);
+        }
+        if src_start > src_end || src_end > self.len {
+            return Err(CopyError::OutOfBounds);
+        }
+        let count = src_end - src_start;
+        if dest > self.len - count {
+            return Err(CopyError::OutOfBounds);
+        }
+        unsafe { core::ptr::copy(self.data.add(src_start), self.data.add(dest), count) };
+        Ok(())
+    }
+
+    /// Returns `true` if the pointer is not null and the raw slice is not empty.
+    ///
+    /// This is a convenience predicate which treats a NULL raw slice like an empty raw slice.
+    pub const fn has_data(&self) -> bool {
+        !self.is_null() && self.len > 0
+    }
+
+    /// Reverses the order of the elements in the memory referenced by the raw slice in-place.
+    ///
+    /// This has no effect if the pointer is null. See [slice::reverse] for more details.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn reverse(&mut self) {
+        if let Some(slice) = unsafe { self.get_mut() } {
+            slice.reverse();
+        }
+    }
+
+    /// Returns a raw slice with the data pointer offset by `byte_offset` bytes and the same
+    /// length.
+    ///
+    /// This is useful if the memory is accessed through a different address space alias, for
+    /// example a DMA-visible mirror of an SRAM region. A NULL raw slice yields a NULL raw slice.
+    /// The data is never dereferenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the aliased memory region is valid and equivalent to
+    ///   the original memory region when the returned raw slice is used.
+    /// - The caller **must** ensure that the offset pointer is properly aligned for `T`.
+    pub const unsafe fn with_address_offset(&self, byte_offset: isize) -> RawSliceMut<T> {
+        if self.is_null() {
+            return RawSliceMut {
+                data: self.data,
+                len: self.len,
+            };
+        }
+        RawSliceMut {
+            data: self.data.wrapping_byte_offset(byte_offset),
+            len: self.len,
+        }
+    }
+
+    /// Converts the raw pointer into a reference to an array with `N` elements.
+    ///
+    /// Returns [None] if the pointer is null or if the length is not equal to `N`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn as_array<'slice, const N: usize>(&self) -> Option<&'slice [T; N]> {
+        if self.data.is_null() || self.len != N {
+            return None;
+        }
+        Some(unsafe { &*(self.data as *const [T; N]) })
+    }
+
+    /// Converts the raw pointer into a mutable reference to an array with `N` elements.
+    ///
+    /// Returns [None] if the pointer is null or if the length is not equal to `N`.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn as_array_mut<'slice, const N: usize>(
+        &mut self,
+    ) -> Option<&'slice mut [T; N]> {
+        if self.data.is_null() || self.len != N {
+            return None;
+        }
+        Some(unsafe { &mut *(self.data as *mut [T; N]) })
+    }
+
+    /// Returns a copy of the raw slice which refers to the same memory.
+    ///
+    /// This is semantically identical to copying the raw slice, but makes the intent of creating a
+    /// second descriptor for the same memory explicit at the call site, for example when handing
+    /// the same buffer to two independent consumers.
+    ///
+    /// Please note that both raw slices allow mutable access, so the caller must ensure that they
+    /// are not used to access the same elements at the same time.
+    pub const fn duplicate(&self) -> Self {
+        Self {
+            data: self.data,
+            len: self.len,
+        }
+    }
+
+    /// Fills the memory referenced by the raw slice with the values returned by `f`.
+    ///
+    /// The element at index `i` is set to `f(i)`. This has no effect if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn fill_with<F: FnMut(usize) -> T>(&mut self, mut f: F) {
+        if let Some(slice) = unsafe { self.get_mut() } {
+            for (i, elem) in slice.iter_mut().enumerate() {
+                *elem = f(i);
+            }
+        }
+    }
+
+    /// Returns the stored length, regardless of whether the pointer is null.
+    ///
+    /// In contrast to [Self::len], this does not check the pointer. The stored length of a NULL
+    /// raw slice has no meaning.
+    pub const fn raw_len(&self) -> usize {
+        self.len
+    }
+
+    /// Updates only the stored length and keeps the pointer.
+    ///
+    /// This is useful for bookkeeping where the pointer stays fixed and only the logical length
+    /// changes, for example after the hardware reports a received count.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the pointer refers to at least `len` valid elements.
+    /// - Setting the length of a NULL raw slice creates an invalid state, which must be followed
+    ///   by setting a valid pointer before the raw slice is used.
+    pub const unsafe fn set_raw_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    /// Merges the raw slice with `next` if `next` starts directly after the end of this raw
+    /// slice.
+    ///
+    /// Returns [None] if either pointer is null or if the memory regions are not adjacent. This
+    /// can be used to coalesce adjacent DMA descriptors. This only compares addresses, the data
+    /// is never dereferenced.
+    pub fn concat_adjacent(&self, next: &RawSliceMut<T>) -> Option<RawSliceMut<T>> {
+        if self.is_null()
+            || next.is_null()
+            || !core::ptr::eq(self.data.wrapping_add(self.len), next.data)
+        {
+            return None;
+        }
+        Some(RawSliceMut {
+            data: self.data,
+            len: self.len.checked_add(next.len)?,
+        })
+    }
+
+    /// Reads the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds. In contrast to
+    /// [Self::get_elem], this returns a copy of the element instead of a reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn read_at(&self, index: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        Some(unsafe { core::ptr::read(self.data.add(index)) })
+    }
+
+    /// Writes `value` to the element at `index`.
+    ///
+    /// Returns [None] if the pointer is null or if `index` is out of bounds and `Some(())`
+    /// otherwise. This allows writing a single element without reconstructing the whole
+    /// mutable slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn write_at(&mut self, index: usize, value: T) -> Option<()>
+    where
+        T: Copy,
+    {
+        if self.is_null() || index >= self.len {
+            return None;
+        }
+        unsafe { core::ptr::write(self.data.add(index), value) };
+        Some(())
+    }
+
+    /// Returns a raw slice with the data pointer offset by `count` elements and the length
+    /// adjusted accordingly, so that the end of the raw slice stays the same.
+    ///
+    /// A positive `count` moves the start towards the end like [Self::advance], and a negative
+    /// `count` moves the start backwards and increases the length. Returns [None] if the pointer
+    /// is null, if `count` is larger than the length or if the new length overflows.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the resulting pointer is still within the original
+    ///   allocation. For a negative `count`, the `-count` elements before the start of the raw
+    ///   slice **must** be part of the same allocation.
+    pub const unsafe fn offset(&mut self, count: isize) -> Option<RawSliceMut<T>> {
+        if self.is_null() {
+            return None;
+        }
+        let len = if count >= 0 {
+            if count as usize > self.len {
+                return None;
+            }
+            self.len - count as usize
+        } else {
+            match self.len.checked_add(count.unsigned_abs()) {
+                Some(len) => len,
+                None => return None,
+            }
+        };
+        Some(RawSliceMut {
+            data: unsafe { self.data.offset(count) },
+            len,
+        })
+    }
+}
+
+/// Returns a NULL raw slice created with [Self::new_nulled].
+///
+/// [Self::dangling] can be used to create an empty but non-NULL raw slice instead.
+impl<T> Default for RawSliceMut<T> {
+    fn default() -> Self {
+        Self::new_nulled()
+    }
+}
+
+/// Compares the data pointer and the length, not the contents.
+impl<T> PartialEq for RawSliceMut<T> {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<T> Eq for RawSliceMut<T> {}
+
+/// Hashes the data pointer and the length, not the contents.
+impl<T> core::hash::Hash for RawSliceMut<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+/// Orders raw slices by the address of the data pointer first and by the length second.
+///
+/// This ordering is based on the identity of the raw slices, not on their contents, and it does
+/// not dereference the data. It is useful to keep a sorted list of buffers for bisecting.
+impl<T> PartialOrd for RawSliceMut<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders raw slices by the address of the data pointer first and by the length second.
+///
+/// This ordering is based on the identity of the raw slices, not on their contents, and it does
+/// not dereference the data.
+impl<T> Ord for RawSliceMut<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.data as usize, self.len).cmp(&(other.data as usize, other.len))
+    }
+}
+
+/// Compares the data pointer and the length, not the contents.
+impl<T> PartialEq<RawSliceMut<T>> for RawSlice<T> {
+    fn eq(&self, other: &RawSliceMut<T>) -> bool {
+        core::ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+/// Compares the data pointer and the length, not the contents.
+impl<T> PartialEq<RawSlice<T>> for RawSliceMut<T> {
+    fn eq(&self, other: &RawSlice<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T: core::fmt::Debug> RawSliceMut<T> {
+    /// Returns an adapter which formats the address and the contents of the raw slice with
+    /// [Debug](core::fmt::Debug).
+    ///
+    /// The [Debug](core::fmt::Debug) implementation of the raw slice only formats the address and
+    /// the length. This adapter can be used to show the contents instead, for example inside
+    /// tests where the data is known to be valid. Only the address and the length are formatted
+    /// if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub unsafe fn debug_contents(&self) -> impl core::fmt::Debug + '_ {
+        DebugContents {
+            name: "RawSliceMut",
+            data: self.data as *const T,
+            len: self.len,
+            // Safety: The caller ensures that the underlying memory is still valid.
+            contents: unsafe { self.get() },
+        }
+    }
+}
+
+/// Only the address and the length are formatted, the data is never dereferenced.
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl<T> defmt::Format for RawSliceMut<T> {
+    fn format(&self, fmt: defmt::Formatter) {
+        if self.is_null() {
+            defmt::write!(fmt, "RawSliceMut {{ null }}");
+        } else {
+            defmt::write!(
+                fmt,
+                "RawSliceMut {{ data: {=usize:#x}, len: {} }}",
+                self.data as usize,
+                self.len
+            );
+        }
+    }
+}
+
+pub type RawBufSliceMut = RawU8SliceMut;
+pub type RawU8SliceMut = RawSliceMut<u8>;
+pub type RawU16SliceMut = RawSliceMut<u16>;
+pub type RawU32SliceMut = RawSliceMut<u32>;
+pub type RawU64SliceMut = RawSliceMut<u64>;
+pub type RawI8SliceMut = RawSliceMut<i8>;
+pub type RawI16SliceMut = RawSliceMut<i16>;
+pub type RawI32SliceMut = RawSliceMut<i32>;
+
+/// This allows using [RawSliceMut] in DMA APIs which expect a [embedded_dma::WriteBuffer].
+///
+/// However, the user still must ensure that any alignment rules for DMA buffers required by
+/// the hardware are met and than any MPU/MMU configuration necessary was also performed.
+unsafe impl<T: embedded_dma::Word> embedded_dma::WriteBuffer for RawSliceMut<T> {
+    type Word = T;
+
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        self.as_write_buffer()
+    }
+}
+
+/// This allows using [RawSliceMut] in DMA APIs which expect a [embedded_dma::ReadBuffer], for
+/// example to transmit data which was prepared in place.
+///
+/// However, the user still must ensure that any alignment rules for DMA buffers required by
+/// the hardware are met and than any MPU/MMU configuration necessary is also performed for this
+/// to work properly.
+unsafe impl<T: embedded_dma::Word> embedded_dma::ReadBuffer for RawSliceMut<T> {
+    type Word = T;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        self.as_read_buffer()
+    }
+}
+
+/// Wrapper around a raw slice which limits the number of elements of a DMA transfer to `MAX`.
+///
+/// Some DMA controllers have a maximum transfer count. The limit is configured with the const
+/// generic `MAX` and checked once when the wrapper is created with [RawSlice::with_max_len] or
+/// [RawSliceMut::with_max_len]. The wrapped raw slice can not be modified afterwards, so the
+/// [embedded_dma::ReadBuffer] and [embedded_dma::WriteBuffer] implementations only contain an
+/// additional debug assertion. This allows catching oversized transfers at the boundary instead
+/// of inside the DMA driver.
+///
+/// ```
+/// use raw_slice::RawBufSlice;
+///
+/// const MAX_TRANSFER_LEN: usize = 4;
+/// let buf = [0u8; 8];
+/// let slice = unsafe { RawBufSlice::new(&buf[..4]) };
+/// assert!(slice.with_max_len::<MAX_TRANSFER_LEN>().is_some());
+/// let slice = unsafe { RawBufSlice::new(&buf) };
+/// assert!(slice.with_max_len::<MAX_TRANSFER_LEN>().is_none());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MaxLenSlice<S, const MAX: usize>(S);
+
+impl<S, const MAX: usize> MaxLenSlice<S, MAX> {
+    /// Returns a reference to the wrapped raw slice.
+    pub const fn inner(&self) -> &S {
+        &self.0
+    }
+
+    /// Returns the wrapped raw slice.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+unsafe impl<S: embedded_dma::ReadBuffer, const MAX: usize> embedded_dma::ReadBuffer
+    for MaxLenSlice<S, MAX>
+{
+    type Word = S::Word;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        let (ptr, len) = unsafe { self.0.read_buffer() };
+        debug_assert!(len <= MAX, "DMA transfer length exceeds the maximum length");
+        (ptr, len)
+    }
+}
+
+unsafe impl<S: embedded_dma::WriteBuffer, const MAX: usize> embedded_dma::WriteBuffer
+    for MaxLenSlice<S, MAX>
+{
+    type Word = S::Word;
+
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        let (ptr, len) = unsafe { self.0.write_buffer() };
+        debug_assert!(len <= MAX, "DMA transfer length exceeds the maximum length");
+        (ptr, len)
+    }
+}
+
+/// Raw slice with a fixed capacity of `N` elements.
+///
+/// In contrast to [RawSlice], the length of this type is guaranteed to never exceed `N`:
+/// [Self::new] takes an array reference with exactly `N` elements and [Self::set] clamps the
+/// length of the stored slice to `N`. [Self::get] therefore reconstructs at most `N` elements.
+/// This is useful for fixed peripheral buffers, for example a DMA FIFO with a fixed size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RawArraySlice<T, const N: usize> {
+    data: *const T,
+    len: usize,
+}
+
+/// Safety: This type MUST be used with mutex to ensure concurrent access is valid.
+unsafe impl<T: Send, const N: usize> Send for RawArraySlice<T, N> {}
+
+impl<T, const N: usize> RawArraySlice<T, N> {
+    /// Creates a new `RawArraySlice<T, N>` from an array reference.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the array outlives this `RawArraySlice<T, N>`.
+    /// - The original array **must not** be mutated while this `RawArraySlice<T, N>` is used.
+    pub const unsafe fn new(data: &[T; N]) -> Self {
+        Self {
+            data: data.as_ptr(),
+            len: N,
+        }
+    }
+
+    /// Creates an empty `RawArraySlice<T, N>`, equivalent to a null pointer with zero length.
+    pub const fn new_nulled() -> Self {
+        Self {
+            data: core::ptr::null(),
+            len: 0,
+        }
+    }
+
+    /// Updates the raw pointer and length to point to a new slice.
+    ///
+    /// The length is clamped to `N`, so only the first `N` elements of a longer slice are
+    /// referenced.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `RawArraySlice<T, N>`.
+    /// - The original slice **must not** be mutated while this `RawArraySlice<T, N>` is used.
+    pub const unsafe fn set(&mut self, data: &[T]) {
+        self.data = data.as_ptr();
+        self.len = if data.len() < N { data.len() } else { N };
+    }
+
+    /// Set the internal data pointer to NULL and also clears the data length.
+    pub const fn set_null(&mut self) {
+        self.data = core::ptr::null();
+        self.len = 0;
+    }
+
+    /// Check whether the internal data pointer is NULL.
+    pub const fn is_null(&self) -> bool {
+        self.data.is_null()
+    }
+
+    /// Returns [None] if the pointer is null and the length of the raw slice otherwise.
+    ///
+    /// The length never exceeds `N`.
+    pub const fn len(&self) -> Option<usize> {
+        if self.is_null() {
+            return None;
+        }
+        Some(self.len)
+    }
+
+    /// Returns [None] if the pointer is null and whether [Self::len] is 0 otherwise.
+    pub const fn is_empty(&self) -> Option<bool> {
+        if self.is_null() {
+            return None;
+        }
+        Some(self.len == 0)
+    }
+
+    /// Returns the capacity `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Converts the raw pointer into a slice with at most `N` elements.
+    ///
+    /// Returns [None] if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid.
+    /// - Using this function after the original slice is dropped results in UB.
+    pub const unsafe fn get(&self) -> Option<&[T]> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts(self.data, self.len) })
+    }
+
+    /// Returns the raw slice without the capacity information.
+    pub const fn as_raw_slice(&self) -> RawSlice<T> {
+        RawSlice {
+            data: self.data,
+            len: self.len,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for RawArraySlice<T, N> {
+    fn default() -> Self {
+        Self::new_nulled()
+    }
+}
+
+/// This allows using [RawArraySlice] in DMA APIs which expect a [embedded_dma::ReadBuffer].
+///
+/// However, the user still must ensure that any alignment rules for DMA buffers required by
+/// the hardware are met and than any MPU/MMU configuration necessary is also performed for this
+/// to work properly.
+unsafe impl<T: embedded_dma::Word, const N: usize> embedded_dma::ReadBuffer
+    for RawArraySlice<T, N>
+{
+    type Word = T;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        (self.data, self.len)
+    }
+}
+
+/// Wrapper around a [RawSlice] which implements [Sync].
+///
+/// The base raw slice types do not implement [Sync], so they can not be shared by reference
+/// across contexts, for example when placing them inside a `static`. This wrapper is an
+/// explicit escape hatch for cases where the user provides external synchronization.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SyncRawSlice<T>(RawSlice<T>);
+
+/// Safety: The creator of this type MUST ensure that concurrent access is properly synchronized.
+unsafe impl<T: Sync> Sync for SyncRawSlice<T> {}
+
+impl<T> SyncRawSlice<T> {
+    /// Creates a new `SyncRawSlice<T>` from a raw slice.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that all accesses to the raw slice from different contexts
+    ///   are properly synchronized, for example with a mutex or a critical section.
+    pub const unsafe fn new(slice: RawSlice<T>) -> Self {
+        Self(slice)
+    }
+
+    /// Returns a reference to the wrapped raw slice.
+    pub const fn inner(&self) -> &RawSlice<T> {
+        &self.0
+    }
+
+    /// Returns the wrapped raw slice.
+    pub const fn into_inner(self) -> RawSlice<T> {
+        self.0
+    }
+}
+
+impl<T> core::ops::Deref for SyncRawSlice<T> {
+    type Target = RawSlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Wrapper around a [RawSlice] which never implements [Send] or [Sync].
+///
+/// This documents that a raw slice is only used within a single thread or context, regardless
+/// of the element type `T`. All methods of [RawSlice] are available through [Deref] and
+/// [DerefMut](core::ops::DerefMut).
+///
+/// [Deref]: core::ops::Deref
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LocalRawSlice<T> {
+    slice: RawSlice<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<T> LocalRawSlice<T> {
+    /// Creates a new `LocalRawSlice<T>` from a raw slice.
+    pub const fn new(slice: RawSlice<T>) -> Self {
+        Self {
+            slice,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped raw slice.
+    pub const fn into_inner(self) -> RawSlice<T> {
+        self.slice
+    }
+}
+
+impl<T> Default for LocalRawSlice<T> {
+    fn default() -> Self {
+        Self::new(RawSlice::default())
+    }
+}
+
+impl<T> core::ops::Deref for LocalRawSlice<T> {
+    type Target = RawSlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.slice
+    }
+}
+
+impl<T> core::ops::DerefMut for LocalRawSlice<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.slice
+    }
+}
+
+/// Raw slice which is tagged with a generation counter.
+///
+/// The tag is stored when the raw slice is created and [Self::get_checked] only returns the
+/// slice if the expected tag matches. Stale raw slices can be invalidated by bumping a global
+/// generation counter whenever the referenced memory is released or reused. This is a
+/// lightweight mitigation for use-after-free bugs, it does not replace the validity
+/// requirements of the raw slice.
+#[cfg(feature = "generation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generation")))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GenRawSlice<T> {
+    slice: RawSlice<T>,
+    tag: u32,
+}
+
+#[cfg(feature = "generation")]
+impl<T> GenRawSlice<T> {
+    /// Creates a new `GenRawSlice<T>` from a slice reference and a generation tag.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the slice outlives this `GenRawSlice<T>` for as long
+    ///   as the generation tag is considered valid.
+    /// - The original slice **must not** be mutated while this `GenRawSlice<T>` is used.
+    pub const unsafe fn new(data: &[T], tag: u32) -> Self {
+        Self {
+            slice: unsafe { RawSlice::new(data) },
+            tag,
+        }
+    }
+
+    /// Returns the generation tag.
+    pub const fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// Returns a reference to the wrapped raw slice.
+    pub const fn inner(&self) -> &RawSlice<T> {
+        &self.slice
+    }
+
+    /// Converts the raw pointer into a slice if the generation tag matches `tag`.
+    ///
+    /// Returns [None] if the generation tag does not match or if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the underlying memory is still valid if the generation
+    ///   tag matches.
+    pub const unsafe fn get_checked(&self, tag: u32) -> Option<&[T]> {
+        if self.tag != tag {
+            return None;
+        }
+        unsafe { self.slice.get() }
+    }
+}
+
+/// Raw slice which is protected by a [critical_section::Mutex].
+///
+/// This packages the recommended synchronization pattern for sharing a raw slice between
+/// different contexts, for example a task and an ISR.
+#[cfg(feature = "critical-section")]
+#[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+pub struct CsRawSlice<T> {
+    slice: critical_section::Mutex<core::cell::Cell<RawSlice<T>>>,
+    _marker: PhantomData<*const T>,
+}
+
+/// Safety: Moving the structure only moves the stored raw slice.
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send> Send for CsRawSlice<T> {}
+
+/// Safety: The stored raw slice is only accessed inside a critical section. [Self::with] hands
+/// out a shared slice to multiple contexts, so `T` must be [Sync] as well.
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send + Sync> Sync for CsRawSlice<T> {}
+
+#[cfg(feature = "critical-section")]
+impl<T> CsRawSlice<T> {
+    /// Creates a new NULL `CsRawSlice<T>`.
+    pub const fn new() -> Self {
+        Self {
+            slice: critical_section::Mutex::new(core::cell::Cell::new(RawSlice::new_nulled())),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Stores a new raw slice inside a critical section.
+    ///
+    /// # Safety
+    ///
+    /// - The caller **must** ensure that the referenced data remains valid until the raw slice
+    ///   is replaced or cleared.
+    /// - The referenced data **must not** be mutated while it is stored.
+    pub unsafe fn set(&self, slice: RawSlice<T>) {
+        critical_section::with(|cs| self.slice.borrow(cs).set(slice));
+    }
+
+    /// Clears the stored raw slice inside a critical section.
+    pub fn clear(&self) {
+        critical_section::with(|cs| self.slice.borrow(cs).set(RawSlice::new_nulled()));
+    }
+
+    /// Enters a critical section, reconstructs the stored slice and passes it to `f`.
+    ///
+    /// [None] is passed to `f` if the stored raw slice is NULL.
+    pub fn with<R>(&self, f: impl FnOnce(Option<&[T]>) -> R) -> R {
+        critical_section::with(|cs| {
+            let cell = self.slice.borrow(cs);
+            // The cell is read without copying it, so `T` does not need to be [Copy].
+            let slice = cell.replace(RawSlice::new_nulled());
+            cell.set(RawSlice {
+                data: slice.data,
+                len: slice.len,
+            });
+            // Safety: The caller of [Self::set] ensured that the data remains valid while it
+            // is stored.
+            f(unsafe { slice.get() })
+        })
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> Default for CsRawSlice<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Raw slice which can be shared and exchanged between contexts without a lock.
+///
+/// This can be used for a simple lock-free handoff of a raw slice from a producer to a consumer,
+/// for example between a task and an ISR on a single-core system. The producer uses
+/// [Self::try_store] to fill the empty slot, and the consumer uses [Self::take] to empty it
+/// again. [Self::take] is the only consumer operation, so a stored raw slice is always taken
+/// exactly once.
+///
+/// The data pointer and the length are stored inside an [AtomicPtr] and an [AtomicUsize]. An
+/// additional slot state is used to claim the slot before the pointer and the length are
+/// accessed, so a torn raw slice with the pointer of one slice and the length of another slice
+/// is never observed. The state is claimed with [Ordering::Acquire] and released with
+/// [Ordering::Release], so the contents of a stored slice are visible to the context which
+/// takes it.
+#[cfg(target_has_atomic = "ptr")]
+#[cfg_attr(docsrs, doc(cfg(target_has_atomic = "ptr")))]
+#[derive(Debug)]
+pub struct AtomicRawSlice<T> {
+    state: AtomicUsize,
+    data: AtomicPtr<T>,
+    len: AtomicUsize,
+    _marker: PhantomData<*const T>,
+}
+
+/// The slot does not contain a raw slice.
+#[cfg(target_has_atomic = "ptr")]
+const SLOT_EMPTY: usize = 0;
+/// A context is currently accessing the pointer and the length.
+#[cfg(target_has_atomic = "ptr")]
+const SLOT_BUSY: usize = 1;
+/// The slot contains a raw slice.
+#[cfg(target_has_atomic = "ptr")]
+const SLOT_FULL: usize = 2;
+
+/// Safety: The pointer and the length are only accessed while the slot is claimed.
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl<T: Send> Send for AtomicRawSlice<T> {}
+
+/// Safety: The pointer and the length are only accessed while the slot is claimed. The producer
+/// might keep using the data of a stored raw slice while the consumer uses it, so `T` must be
+/// [Sync] as well.
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl<T: Send + Sync> Sync for AtomicRawSlice<T> {}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> AtomicRawSlice<T> {
+    /// Creates a new `AtomicRawSlice<T>` from a raw slice.
+    pub const fn new(slice: RawSlice<T>) -> Self {
+        Self {
+            state: AtomicUsize::new(if slice.is_null() {
+                SLOT_EMPTY
+            } else {
+                SLOT_FULL
+            }),
+            data: AtomicPtr::new(slice.data as *mut T),
+            len: AtomicUsize::new(slice.len),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a NULL `AtomicRawSlice<T>`.
+    pub const fn new_nulled() -> Self {
+        Self::new(RawSlice::new_nulled())
+    }
+
+    /// Tries to claim the slot if it is in the `from` state.
+    fn claim(&self, from: usize) -> bool {
+        self.state
+            .compare_exchange(from, SLOT_BUSY, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    /// Writes the raw slice into a claimed slot and releases the slot.
+    fn write_and_release(&self, slice: RawSlice<T>) {
+        self.data.store(slice.data as *mut T, Ordering::Relaxed);
+        self.len.store(slice.len, Ordering::Relaxed);
+        let state = if slice.is_null() {
+            SLOT_EMPTY
+        } else {
+            SLOT_FULL
+        };
+        self.state.store(state, Ordering::Release);
+    }
+
+    /// Reads the raw slice from a claimed slot.
+    fn read(&self) -> RawSlice<T> {
+        RawSlice {
+            data: self.data.load(Ordering::Relaxed),
+            len: self.len.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Stores a raw slice if the slot is empty.
+    ///
+    /// This is the producer side of a full handoff. Storing a NULL raw slice into an empty slot
+    /// keeps the slot empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provided raw slice if the slot already contains a raw slice or if another
+    /// context is currently accessing the slot.
+    pub fn try_store(&self, slice: RawSlice<T>) -> Result<(), RawSlice<T>> {
+        if !self.claim(SLOT_EMPTY) {
+            return Err(slice);
+        }
+        self.write_and_release(slice);
+        Ok(())
+    }
+
+    /// Stores a raw slice, replacing the raw slice inside the slot if there is one.
+    ///
+    /// This spins while another context is currently accessing the slot. On a single-core system,
+    /// this function **must not** be called from a context which can preempt another context
+    /// accessing the slot, for example an ISR, because it would spin forever. [Self::try_store]
+    /// does not have this restriction.
+    pub fn store(&self, slice: RawSlice<T>) {
+        while !self.claim(SLOT_EMPTY) && !self.claim(SLOT_FULL) {
+            core::hint::spin_loop();
+        }
+        self.write_and_release(slice);
+    }
+
+    /// Takes the raw slice and leaves an empty slot in its place.
+    ///
+    /// This is the consumer side of a full handoff. Returns a NULL raw slice if the slot is empty.
+    /// This includes the case where a producer is currently storing a raw slice, because that raw
+    /// slice is not published yet, and the case where another consumer is currently taking the
+    /// raw slice.
+    pub fn take(&self) -> RawSlice<T> {
+        if !self.claim(SLOT_FULL) {
+            return RawSlice::new_nulled();
+        }
+        let slice = self.read();
+        self.write_and_release(RawSlice::new_nulled());
+        slice
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Default for AtomicRawSlice<T> {
+    fn default() -> Self {
+        Self::new_nulled()
+    }
+}
+
+/// Formats the address and the contents of a raw slice, created with `debug_contents`.
+struct DebugContents<'slice, T> {
+    name: &'static str,
+    data: *const T,
+    len: usize,
+    contents: Option<&'slice [T]>,
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for DebugContents<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct(self.name);
+        debug.field("data", &self.data);
+        match self.contents {
+            Some(contents) => debug.field("contents", &contents),
+            None => debug.field("len", &self.len),
+        };
+        debug.finish()
+    }
+}
+
+/// Converts a range into a start and end index, using `len` for an unbounded end.
+fn range_to_start_end<R: RangeBounds<usize>>(range: R, len: usize) -> Option<(usize, usize)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    Some((start, end))
+}
+
+/// Iterator over the elements of a raw slice.
+///
+/// Created by [RawSlice::iter] and [RawSliceMut::iter]. The underlying memory must remain
+/// valid for the lifetime of the iterator.
+#[derive(Debug, Clone)]
+pub struct RawSliceIter<'slice, T> {
+    data: *const T,
+    len: usize,
+    _marker: PhantomData<&'slice T>,
+}
+
+impl<'slice, T> RawSliceIter<'slice, T> {
+    const fn new(data: *const T, len: usize) -> Self {
+        Self {
+            data,
+            len: if data.is_null() { 0 } else { len },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'slice, T> Iterator for RawSliceIter<'slice, T> {
+    type Item = &'slice T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // Safety: The creator of the iterator ensured that the underlying memory is valid.
+        let item = unsafe { &*self.data };
+        self.data = unsafe { self.data.add(1) };
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for RawSliceIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // Safety: The creator of the iterator ensured that the underlying memory is valid.
+        Some(unsafe { &*self.data.add(self.len) })
+    }
+}
+
+impl<T> ExactSizeIterator for RawSliceIter<'_, T> {}
+
+impl<T> core::iter::FusedIterator for RawSliceIter<'_, T> {}
+
+/// Raw slice which is known to be valid for the lifetime `'slice`.
+///
+/// Created by [RawSlice::assume_valid] and [RawSliceMut::assume_valid]. The unsafe constructor
+/// is the only point where the caller has to guarantee the validity of the underlying memory.
+/// Afterwards, the lifetime `'slice` ties all borrows to the scope in which the memory is known
+/// to be valid, so iterating over the elements with [IntoIterator] or [Self::iter] is safe.
+#[derive(Debug, Copy, Clone)]
+pub struct ValidRawSlice<'slice, T> {
+    data: *const T,
+    len: usize,
+    _marker: PhantomData<&'slice T>,
+}
+
+impl<'slice, T> ValidRawSlice<'slice, T> {
+    /// Returns an iterator over the elements of the raw slice.
+    ///
+    /// The iterator yields nothing if the pointer is null.
+    pub fn iter(&self) -> RawSliceIter<'slice, T> {
+        RawSliceIter::new(self.data, self.len)
+    }
+
+    /// Returns [None] if the pointer is null and the length of the raw slice otherwise.
+    pub const fn len(&self) -> Option<usize> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(self.len)
+    }
+
+    /// Returns [None] if the pointer is null and whether [Self::len] is 0 otherwise.
+    pub const fn is_empty(&self) -> Option<bool> {
+        if self.data.is_null() {
+            return None;
+        }
+        Some(self.len == 0)
+    }
+}
+
+impl<'slice, T> IntoIterator for ValidRawSlice<'slice, T> {
+    type Item = &'slice T;
+    type IntoIter = RawSliceIter<'slice, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'slice, T> IntoIterator for &ValidRawSlice<'slice, T> {
+    type Item = &'slice T;
+    type IntoIter = RawSliceIter<'slice, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrow-checked view of a raw slice with the lifetime `'slice`.
+///
+/// Created by [RawSlice::view] and [RawSliceMut::view]. The view wraps a regular slice
+/// reference and dereferences to `[T]`, so all further accesses are safe and checked by the
+/// borrow checker.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RawSliceView<'slice, T>(&'slice [T]);
+
+impl<'slice, T> RawSliceView<'slice, T> {
+    /// Returns the wrapped slice with the full lifetime `'slice`.
+    pub const fn as_slice(&self) -> &'slice [T] {
+        self.0
+    }
+}
+
+impl<T> core::ops::Deref for RawSliceView<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+/// Iterator over non-overlapping chunks of a raw slice.
+///
+/// Created by [RawSlice::chunks]. Each chunk has a length of `chunk_size` elements, except for
+/// the last chunk which might be shorter.
+#[derive(Debug, Clone)]
+pub struct RawSliceChunks<'slice, T> {
+    remaining: RawSlice<T>,
+    chunk_size: usize,
+    _marker: PhantomData<&'slice T>,
+}
+
+impl<T> Iterator for RawSliceChunks<'_, T> {
+    type Item = RawSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.len == 0 {
+            return None;
+        }
+        let chunk_len = self.chunk_size.min(self.remaining.len);
+        let chunk = RawSlice {
+            data: self.remaining.data,
+            len: chunk_len,
+        };
+        // Safety: The creator of the iterator ensured that the underlying memory is valid, and
+        // the new pointer is at most one element past the end of the raw slice.
+        unsafe { self.remaining.advance(chunk_len) };
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len.div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RawSliceChunks<'_, T> {}
+
+impl<T> core::iter::FusedIterator for RawSliceChunks<'_, T> {}
+
+/// Iterator over non-overlapping chunks of a raw slice with exactly `chunk_size` elements.
+///
+/// Created by [RawSlice::chunks_exact]. Trailing elements which do not fill a whole chunk are
+/// available with [Self::remainder].
+#[derive(Debug, Clone)]
+pub struct RawSliceChunksExact<'slice, T> {
+    remaining: RawSlice<T>,
+    remainder: RawSlice<T>,
+    chunk_size: usize,
+    _marker: PhantomData<&'slice T>,
+}
+
+impl<T> RawSliceChunksExact<'_, T> {
+    /// Returns the trailing elements which are not yielded by the iterator.
+    ///
+    /// The returned raw slice is NULL if the original raw slice was NULL.
+    pub const fn remainder(&self) -> RawSlice<T> {
+        RawSlice {
+            data: self.remainder.data,
+            len: self.remainder.len,
+        }
+    }
+}
+
+impl<T> Iterator for RawSliceChunksExact<'_, T> {
+    type Item = RawSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.len == 0 {
+            return None;
+        }
+        let chunk = RawSlice {
+            data: self.remaining.data,
+            len: self.chunk_size,
+        };
+        // Safety: The creator of the iterator ensured that the underlying memory is valid, and
+        // the new pointer is at most one element past the end of the raw slice.
+        unsafe { self.remaining.advance(self.chunk_size) };
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len / self.chunk_size;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RawSliceChunksExact<'_, T> {}
+
+impl<T> core::iter::FusedIterator for RawSliceChunksExact<'_, T> {}
+
+/// Checks whether raw slice metadata can be reinterpreted from `T` to `U`.
+#[cfg(feature = "bytemuck")]
+fn check_pod_cast<T, U>(data: *const T, len: usize) -> Result<usize, bytemuck::PodCastError> {
+    if core::mem::size_of::<T>() == 0 || core::mem::size_of::<U>() == 0 {
+        return Err(bytemuck::PodCastError::SizeMismatch);
+    }
+    if core::mem::align_of::<U>() > core::mem::align_of::<T>()
+        && data as usize % core::mem::align_of::<U>() != 0
+    {
+        return Err(bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned);
+    }
+    let byte_len = len
+        .checked_mul(core::mem::size_of::<T>())
+        .ok_or(bytemuck::PodCastError::SizeMismatch)?;
+    if byte_len % core::mem::size_of::<U>() != 0 {
+        return Err(bytemuck::PodCastError::OutputSliceWouldHaveSlop);
+    }
+    Ok(byte_len / core::mem::size_of::<U>())
+}
+
+/// Checks whether a raw byte slice can be reinterpreted as a raw slice of `U` and returns the
+/// new length.
+fn check_bytes_cast<U>(data: *const u8, len: usize) -> Result<usize, FromBytesError> {
+    if data as usize % core::mem::align_of::<U>() != 0 {
+        return Err(FromBytesError::Alignment);
+    }
+    let size = core::mem::size_of::<U>();
+    if size == 0 || len % size != 0 {
+        return Err(FromBytesError::Size);
+    }
+    Ok(len / size)
+}
+
+macro_rules! impl_word_conversions {
+    ($($word:ty),+) => {
+        $(
+            /// Length and alignment checked conversion from a raw byte slice.
+            ///
+            /// A NULL raw slice yields a NULL raw slice.
+            impl TryFrom<RawSlice<u8>> for RawSlice<$word> {
+                type Error = FromBytesError;
+
+                fn try_from(slice: RawSlice<u8>) -> Result<Self, Self::Error> {
+                    if slice.is_null() {
+                        return Ok(Self::new_nulled());
+                    }
+                    Ok(Self {
+                        data: slice.data as *const $word,
+                        len: check_bytes_cast::<$word>(slice.data, slice.len)?,
+                    })
+                }
+            }
+
+            /// Length and alignment checked conversion from a mutable raw byte slice.
+            ///
+            /// A NULL raw slice yields a NULL raw slice.
+            impl TryFrom<RawSliceMut<u8>> for RawSliceMut<$word> {
+                type Error = FromBytesError;
+
+                fn try_from(slice: RawSliceMut<u8>) -> Result<Self, Self::Error> {
+                    if slice.is_null() {
+                        return Ok(Self::new_nulled());
+                    }
+                    Ok(Self {
+                        data: slice.data as *mut $word,
+                        len: check_bytes_cast::<$word>(slice.data, slice.len)?,
+                    })
+                }
+            }
+
+            /// Conversion to a raw byte slice covering the same memory region.
+            impl From<RawSlice<$word>> for RawSlice<u8> {
+                fn from(slice: RawSlice<$word>) -> Self {
+                    slice.as_bytes()
+                }
+            }
+
+            /// Conversion to a mutable raw byte slice covering the same memory region.
+            impl From<RawSliceMut<$word>> for RawSliceMut<u8> {
+                fn from(mut slice: RawSliceMut<$word>) -> Self {
+                    slice.as_bytes_mut()
+                }
+            }
+        )+
+    };
+}
+
+impl_word_conversions!(u16, u32, u64);
+
+/// Error type for copy operations between raw slices and regular slices.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CopyError {
+    /// The raw slice is NULL.
+    Null,
+    /// The length of the raw slice and the length of the other slice are not equal.
+    LengthMismatch,
+    /// A range is out of bounds of the raw slice.
+    OutOfBounds,
+}
+
+impl core::fmt::Display for CopyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CopyError::Null => write!(f, "raw slice is NULL"),
+            CopyError::LengthMismatch => write!(f, "slice lengths do not match"),
+            CopyError::OutOfBounds => write!(f, "range out of bounds"),
+        }
+    }
+}
+
+impl core::error::Error for CopyError {}
+
+/// Error type for reinterpreting a raw byte slice as a raw slice of a larger element type.
+///
+/// Returned by the [TryFrom] conversions between the raw slice type aliases, for example from
+/// [RawU8Slice] to [RawU32Slice], and by `RawU8Slice::cast_from_bytes`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FromBytesError {
+    /// The pointer is not properly aligned for the target type.
+    Alignment,
+    /// The length is not a multiple of the size of the target type.
+    Size,
+}
+
+impl core::fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromBytesError::Alignment => write!(f, "raw slice is not aligned for target type"),
+            FromBytesError::Size => write!(f, "raw slice length is not a multiple of target size"),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesError {}
+
+/// Error type for swapping the contents of two mutable raw slices.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SwapError {
+    /// One of the raw slices is NULL.
+    Null,
+    /// The lengths of the raw slices are not equal.
+    LengthMismatch,
+}
+
+impl core::fmt::Display for SwapError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SwapError::Null => write!(f, "raw slice is NULL"),
+            SwapError::LengthMismatch => write!(f, "slice lengths do not match"),
+        }
+    }
+}
+
+impl core::error::Error for SwapError {}
+
+/// Error type for operations which require a raw slice with a non-NULL pointer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NullSliceError;
+
+impl core::fmt::Display for NullSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "raw slice is NULL")
+    }
+}
+
+impl core::error::Error for NullSliceError {}
+
+/// Error type for creating a raw slice which does not lie within a permitted memory region.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RegionError;
+
+impl core::fmt::Display for RegionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "slice is not within the memory region")
+    }
+}
+
+impl core::error::Error for RegionError {}
+
+/// Error type for creating a raw slice from a pointer which is not properly aligned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlignError;
+
+impl core::fmt::Display for AlignError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "pointer is not properly aligned")
+    }
+}
+
+impl core::error::Error for AlignError {}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "RawSlice")]
+    struct Metadata {
+        data: usize,
+        len: usize,
+    }
+
+    impl<T> serde::Serialize for RawSlice<T> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Metadata {
+                data: self.data as usize,
+                len: self.len,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T> serde::Deserialize<'de> for RawSlice<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let metadata = Metadata::deserialize(deserializer)?;
+            Ok(Self {
+                data: metadata.data as *const T,
+                len: metadata.len,
+            })
+        }
+    }
+
+    impl<T> serde::Serialize for RawSliceMut<T> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Metadata {
+                data: self.data as usize,
+                len: self.len,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T> serde::Deserialize<'de> for RawSliceMut<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let metadata = Metadata::deserialize(deserializer)?;
+            Ok(Self {
+                data: metadata.data as *mut T,
+                len: metadata.len,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_basic() {
+        let slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(slice_raw.len().unwrap(), 4);
+        assert!(!slice_raw.is_null());
+        assert!(!slice_raw.is_empty().unwrap());
+        assert_eq!(slice_raw.len().unwrap(), 4);
+        let slice_read_back = unsafe { slice_raw.get().unwrap() };
+        assert_eq!(slice_read_back, slice);
+        slice_raw.set_null();
+        generic_empty_test(&slice_raw);
+    }
+
+    #[test]
+    pub fn test_empty() {
+        let empty = RawBufSlice::new_nulled();
+        generic_empty_test(&empty);
+    }
+
+    #[test]
+    pub fn test_empty_mut() {
+        let mut empty = RawBufSliceMut::new_nulled();
+        generic_empty_test_mut(&mut empty);
+    }
+
+    #[test]
+    pub fn test_clonable() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let slice_copied = slice_raw;
+        assert_eq!(slice_copied, slice_raw);
+    }
+
+    #[test]
+    pub fn test_basic_mut() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(slice_raw.len().unwrap(), 4);
+        assert!(!slice_raw.is_null());
+        assert!(!slice_raw.is_empty().unwrap());
+        assert_eq!(slice_raw.len().unwrap(), 4);
+        let slice_read_back = unsafe { slice_raw.get().unwrap() };
+        assert_eq!(slice_read_back, slice);
+        let mut_slice_read_back = unsafe { slice_raw.get_mut().unwrap() };
+        assert_eq!(slice_read_back, mut_slice_read_back);
+        mut_slice_read_back[0] = 5;
+        assert_eq!(slice[0], 5);
+        slice_raw.set_null();
+        generic_empty_test_mut(&mut slice_raw);
+    }
+
+    #[test]
+    pub fn test_from_raw_parts() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::from_raw_parts(slice.as_ptr(), slice.len()) };
+        assert_eq!(slice_raw, unsafe { RawBufSlice::new(&slice) });
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, slice);
+        let slice_raw = unsafe { RawBufSlice::from_raw_parts(core::ptr::null(), 4) };
+        generic_empty_test(&slice_raw);
+    }
+
+    #[test]
+    pub fn test_from_raw_parts_mut() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw =
+            unsafe { RawBufSliceMut::from_raw_parts(slice.as_mut_ptr(), slice.len()) };
+        unsafe { slice_raw.get_mut().unwrap()[0] = 5 };
+        assert_eq!(slice[0], 5);
+        let mut slice_raw = unsafe { RawBufSliceMut::from_raw_parts(core::ptr::null_mut(), 4) };
+        generic_empty_test_mut(&mut slice_raw);
+    }
+
+    #[test]
+    pub fn test_raw_parts_round_trip() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let (data, len) = slice_raw.into_raw_parts();
+        assert_eq!(data, slice.as_ptr());
+        assert_eq!(len, 4);
+        assert_eq!(unsafe { RawBufSlice::from_raw_parts(data, len) }, slice_raw);
+        assert_eq!(
+            RawBufSlice::new_nulled().into_raw_parts(),
+            (core::ptr::null(), 0)
+        );
+    }
+
+    #[test]
+    pub fn test_raw_parts_round_trip_mut() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        let (data, len) = slice_raw.into_raw_parts();
+        assert_eq!(len, 4);
+        let mut slice_raw = unsafe { RawBufSliceMut::from_raw_parts(data, len) };
+        assert_eq!(unsafe { slice_raw.get_mut().unwrap() }, &[1, 2, 3, 4]);
+        assert_eq!(
+            RawBufSliceMut::new_nulled().into_raw_parts(),
+            (core::ptr::null_mut(), 0)
+        );
+    }
+
+    #[test]
+    pub fn test_as_ptr() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(slice_raw.as_ptr(), slice.as_ptr());
+        assert!(RawBufSlice::new_nulled().as_ptr().is_null());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.as_ptr(), slice_raw_mut.as_mut_ptr());
+        assert_eq!(slice_raw_mut.as_mut_ptr(), slice.as_mut_ptr());
+        assert!(RawBufSliceMut::new_nulled().as_mut_ptr().is_null());
+    }
+
+    #[test]
+    pub fn test_downgrade() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        let slice_raw = slice_raw_mut.downgrade();
+        assert_eq!(slice_raw.as_ptr(), slice_raw_mut.as_ptr());
+        assert_eq!(slice_raw.len(), slice_raw_mut.len());
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, &[1, 2, 3, 4]);
+        generic_empty_test(&RawBufSliceMut::new_nulled().downgrade());
+    }
+
+    #[test]
+    pub fn test_as_non_null() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(
+            slice_raw.as_non_null().unwrap().as_ptr() as *const u8,
+            slice.as_ptr()
+        );
+        assert!(RawBufSlice::new_nulled().as_non_null().is_none());
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(
+            slice_raw_mut.as_non_null().unwrap().as_ptr(),
+            slice.as_mut_ptr()
+        );
+        assert!(RawBufSliceMut::new_nulled().as_non_null().is_none());
+    }
+
+    #[test]
+    pub fn test_layout() {
+        assert_eq!(
+            core::mem::size_of::<RawBufSlice>(),
+            core::mem::size_of::<&[u8]>()
+        );
+        assert_eq!(
+            core::mem::size_of::<RawU32SliceMut>(),
+            core::mem::size_of::<&mut [u32]>()
+        );
+    }
+
+    #[test]
+    pub fn test_get_unchecked() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.get_unchecked() }, &[1, 2, 3, 4]);
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(unsafe { slice_raw_mut.get_unchecked() }, &[1, 2, 3, 4]);
+        unsafe { slice_raw_mut.get_unchecked_mut()[3] = 5 };
+        assert_eq!(slice[3], 5);
+    }
+
+    #[test]
+    pub fn test_get_elem() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.get_elem(0) }, Some(&1));
+        assert_eq!(unsafe { slice_raw.get_elem(3) }, Some(&4));
+        assert!(unsafe { slice_raw.get_elem(4) }.is_none());
+        let nulled = RawBufSlice::new_nulled();
+        assert!(unsafe { nulled.get_elem(0) }.is_none());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(unsafe { slice_raw_mut.get_elem(1) }, Some(&2));
+        *unsafe { slice_raw_mut.get_elem_mut(1) }.unwrap() = 5;
+        assert!(unsafe { slice_raw_mut.get_elem_mut(4) }.is_none());
+        assert!(unsafe { RawBufSliceMut::new_nulled().get_elem_mut(0) }.is_none());
+        assert_eq!(slice[1], 5);
+    }
+
+    #[test]
+    pub fn test_split_at() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let (first, second) = unsafe { slice_raw.split_at(1) }.unwrap();
+        assert_eq!(unsafe { first.get().unwrap() }, &[1]);
+        assert_eq!(unsafe { second.get().unwrap() }, &[2, 3, 4]);
+        let (first, second) = unsafe { slice_raw.split_at(0) }.unwrap();
+        assert_eq!(first.as_ptr(), slice.as_ptr());
+        assert_eq!(first.len(), Some(0));
+        assert_eq!(second, slice_raw);
+        let (first, second) = unsafe { slice_raw.split_at(4) }.unwrap();
+        assert_eq!(first, slice_raw);
+        assert_eq!(second.as_ptr(), slice.as_ptr_range().end);
+        assert_eq!(second.len(), Some(0));
+        assert!(unsafe { slice_raw.split_at(5) }.is_none());
+        assert!(unsafe { RawBufSlice::new_nulled().split_at(0) }.is_none());
+    }
+
+    #[test]
+    pub fn test_split_at_mut() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        let (mut first, mut second) = unsafe { slice_raw.split_at_mut(2) }.unwrap();
+        unsafe { first.get_mut().unwrap()[0] = 5 };
+        unsafe { second.get_mut().unwrap()[0] = 6 };
+        let (first, second) = unsafe { slice_raw.split_at_mut(0) }.unwrap();
+        assert_eq!(first.len(), Some(0));
+        assert_eq!(second.len(), Some(4));
+        let (first, second) = unsafe { slice_raw.split_at_mut(4) }.unwrap();
+        assert_eq!(first.len(), Some(4));
+        assert_eq!(second.len(), Some(0));
+        assert!(unsafe { slice_raw.split_at_mut(5) }.is_none());
+        assert!(unsafe { RawBufSliceMut::new_nulled().split_at_mut(0) }.is_none());
+        assert_eq!(slice, [5, 2, 6, 4]);
+    }
+
+    #[test]
+    pub fn test_subslice() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let subslice = unsafe { slice_raw.subslice(1, 3) }.unwrap();
+        assert_eq!(unsafe { subslice.get().unwrap() }, &[2, 3]);
+        assert_eq!(unsafe { slice_raw.subslice(0, 4) }, Some(slice_raw));
+        assert_eq!(unsafe { slice_raw.subslice(2, 2) }.unwrap().len(), Some(0));
+        assert!(unsafe { slice_raw.subslice(3, 2) }.is_none());
+        assert!(unsafe { slice_raw.subslice(0, 5) }.is_none());
+        assert!(unsafe { RawBufSlice::new_nulled().subslice(0, 0) }.is_none());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        let mut subslice = unsafe { slice_raw_mut.subslice_mut(2, 4) }.unwrap();
+        unsafe { subslice.get_mut().unwrap().copy_from_slice(&[5, 6]) };
+        assert!(unsafe { slice_raw_mut.subslice_mut(0, 5) }.is_none());
+        assert_eq!(slice, [1, 2, 5, 6]);
+    }
+
+    #[test]
+    pub fn test_eq_slice() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert!(unsafe { slice_raw.eq_slice(&[1, 2, 3, 4]) });
+        assert!(!unsafe { slice_raw.eq_slice(&[1, 2, 3]) });
+        assert!(!unsafe { slice_raw.eq_slice(&[1, 2, 3, 5]) });
+        assert!(!unsafe { RawBufSlice::new_nulled().eq_slice(&[]) });
+    }
+
+    #[test]
+    pub fn test_eq_slice_distinct_arrays() {
+        let slice_0 = [1, 2, 3, 4];
+        let slice_1 = [1, 2, 3, 4];
+        let slice_raw_0 = unsafe { RawBufSlice::new(&slice_0) };
+        let slice_raw_1 = unsafe { RawBufSlice::new(&slice_1) };
+        assert_ne!(slice_raw_0, slice_raw_1);
+        assert!(unsafe { slice_raw_0.eq_slice(slice_raw_1.get().unwrap()) });
+        let mut slice_2 = [1, 2, 3, 4];
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice_2) };
+        assert!(unsafe { slice_raw_mut.eq_slice(&slice_0) });
+        assert!(!unsafe { slice_raw_mut.eq_slice(&[1, 2]) });
+        assert!(!unsafe { RawBufSliceMut::new_nulled().eq_slice(&[]) });
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn test_serde() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let serialized = serde_json::to_string(&slice_raw).unwrap();
+        let deserialized: RawBufSlice = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, slice_raw);
+        let serialized = serde_json::to_string(&RawBufSliceMut::new_nulled()).unwrap();
+        let mut deserialized: RawBufSliceMut = serde_json::from_str(&serialized).unwrap();
+        generic_empty_test_mut(&mut deserialized);
+    }
+
+    #[test]
+    pub fn test_basic_i16() {
+        let slice: [i16; 4] = [-1, 2, -3, 4];
+        let slice_raw = unsafe { RawI16Slice::new(&slice) };
+        assert_eq!(slice_raw.len().unwrap(), 4);
+        assert!(!slice_raw.is_null());
+        assert!(!slice_raw.is_empty().unwrap());
+        let slice_read_back = unsafe { slice_raw.get().unwrap() };
+        assert_eq!(slice_read_back, slice);
+        let (data, len) = unsafe { embedded_dma::ReadBuffer::read_buffer(&slice_raw) };
+        assert_eq!(data, slice.as_ptr());
+        assert_eq!(len, 4);
+    }
+
+    #[test]
+    pub fn test_basic_i16_mut() {
+        let mut slice: [i16; 4] = [-1, 2, -3, 4];
+        let mut slice_raw = unsafe { RawI16SliceMut::new(&mut slice) };
+        let mut_slice_read_back = unsafe { slice_raw.get_mut().unwrap() };
+        mut_slice_read_back[0] = -5;
+        let (data, len) = unsafe { embedded_dma::WriteBuffer::write_buffer(&mut slice_raw) };
+        assert_eq!(data, slice.as_mut_ptr());
+        assert_eq!(len, 4);
+        assert_eq!(slice[0], -5);
+    }
+
+    #[test]
+    pub fn test_basic_u64() {
+        let mut slice: [u64; 2] = [1, u64::MAX];
+        let slice_raw = unsafe { RawU64Slice::new(&slice) };
+        assert_eq!(slice_raw.len().unwrap(), 2);
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, slice);
+        let (data, len) = unsafe { embedded_dma::ReadBuffer::read_buffer(&slice_raw) };
+        assert_eq!(data, slice.as_ptr());
+        assert_eq!(len, 2);
+        let mut slice_raw_mut = unsafe { RawU64SliceMut::new(&mut slice) };
+        unsafe { slice_raw_mut.get_mut().unwrap()[0] = 2 };
+        let (data, len) = unsafe { embedded_dma::WriteBuffer::write_buffer(&mut slice_raw_mut) };
+        assert_eq!(data, slice.as_mut_ptr());
+        assert_eq!(len, 2);
+        assert_eq!(slice[0], 2);
+    }
+
+    #[test]
+    pub fn test_dma_buffers() {
+        fn read_buffer<B: embedded_dma::ReadBuffer<Word = u8>>(buf: &B) -> (*const u8, usize) {
+            unsafe { buf.read_buffer() }
+        }
+        fn write_buffer<B: embedded_dma::WriteBuffer<Word = u8>>(buf: &mut B) -> (*mut u8, usize) {
+            unsafe { buf.write_buffer() }
+        }
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawU8Slice::new(&slice) };
+        assert_eq!(read_buffer(&slice_raw), (slice.as_ptr(), 4));
+        assert_eq!(
+            read_buffer(&RawU8Slice::new_nulled()),
+            (core::ptr::null(), 0)
+        );
+        let mut slice_raw_mut = unsafe { RawU8SliceMut::new(&mut slice) };
+        assert_eq!(write_buffer(&mut slice_raw_mut), (slice.as_mut_ptr(), 4));
+        assert_eq!(read_buffer(&slice_raw_mut), (slice.as_ptr(), 4));
+        assert_eq!(
+            read_buffer(&RawU8SliceMut::new_nulled()),
+            (core::ptr::null(), 0)
+        );
+    }
+
+    #[test]
+    pub fn test_byte_len() {
+        let mut slice: [u32; 4] = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawU32Slice::new(&slice) };
+        assert_eq!(slice_raw.byte_len(), Some(16));
+        assert!(RawU32Slice::new_nulled().byte_len().is_none());
+        let overflowing = unsafe { RawU32Slice::from_raw_parts(slice.as_ptr(), usize::MAX) };
+        assert!(overflowing.byte_len().is_none());
+        let slice_raw_mut = unsafe { RawU32SliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.byte_len(), Some(16));
+        assert!(RawU32SliceMut::new_nulled().byte_len().is_none());
+    }
+
+    #[test]
+    pub fn test_is_aligned_to() {
+        let mut slice: [u32; 4] = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawU32Slice::new(&slice) };
+        assert_eq!(slice_raw.is_aligned_to(1), Some(true));
+        assert_eq!(slice_raw.is_aligned_to(4), Some(true));
+        let bytes = unsafe { RawU8Slice::from_raw_parts((slice.as_ptr() as *const u8).add(1), 3) };
+        assert_eq!(bytes.is_aligned_to(1), Some(true));
+        assert_eq!(bytes.is_aligned_to(2), Some(false));
+        assert!(RawU32Slice::new_nulled().is_aligned_to(4).is_none());
+        let slice_raw_mut = unsafe { RawU32SliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.is_aligned_to(4), Some(true));
+        assert!(RawU32SliceMut::new_nulled().is_aligned_to(4).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_is_aligned_to_not_power_of_two() {
+        RawU32Slice::new_nulled().is_aligned_to(3);
+    }
+
+    #[test]
+    pub fn test_cast() {
+        let mut slice: [u32; 2] = [0x01020304, 0x05060708];
+        let slice_raw = unsafe { RawU32Slice::new(&slice) };
+        let bytes = unsafe { slice_raw.cast::<u8>() };
+        assert_eq!(bytes.len(), Some(8));
+        assert_eq!(bytes.as_ptr(), slice.as_ptr() as *const u8);
+        let words = unsafe { bytes.cast::<u32>() };
+        assert_eq!(words, slice_raw);
+        let truncated = unsafe { bytes.subslice(0, 7).unwrap().cast::<u32>() };
+        assert_eq!(truncated.len(), Some(1));
+        assert!(unsafe { RawU32Slice::new_nulled().cast::<u8>() }.is_null());
+        let slice_raw_mut = unsafe { RawU32SliceMut::new(&mut slice) };
+        let mut bytes_mut = unsafe { slice_raw_mut.cast::<u8>() };
+        assert_eq!(bytes_mut.len(), Some(8));
+        unsafe { bytes_mut.get_mut().unwrap().fill(0) };
+        assert_eq!(slice, [0, 0]);
+    }
+
+    #[test]
+    pub fn test_as_bytes() {
+        let mut slice: [u16; 2] = [0x0102, 0x0304];
+        let slice_raw = unsafe { RawU16Slice::new(&slice) };
+        let bytes = slice_raw.as_bytes();
+        assert_eq!(bytes.len(), Some(4));
+        assert_eq!(bytes.as_ptr(), slice.as_ptr() as *const u8);
+        assert!(RawU16Slice::new_nulled().as_bytes().is_null());
+        let mut slice_raw_mut = unsafe { RawU16SliceMut::new(&mut slice) };
+        let mut bytes_mut = slice_raw_mut.as_bytes_mut();
+        assert_eq!(bytes_mut.len(), Some(4));
+        unsafe { bytes_mut.get_mut().unwrap().fill(0xff) };
+        assert_eq!(slice, [0xffff, 0xffff]);
+        assert!(RawU16SliceMut::new_nulled().as_bytes_mut().is_null());
+        let overflowing = unsafe { RawU16Slice::from_raw_parts(slice.as_ptr(), usize::MAX) };
+        assert!(overflowing.as_bytes().is_null());
+        assert!(unsafe { overflowing.cast::<u8>() }.is_null());
+        let mut overflowing_mut =
+            unsafe { RawU16SliceMut::from_raw_parts(slice.as_mut_ptr(), usize::MAX) };
+        assert!(overflowing_mut.as_bytes_mut().is_null());
+        assert!(unsafe { overflowing_mut.cast::<u8>() }.is_null());
+    }
+
+    #[test]
+    pub fn test_truncate() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
+        slice_raw.truncate(5);
+        assert_eq!(slice_raw.len(), Some(4));
+        slice_raw.truncate(2);
+        assert_eq!(slice_raw.len(), Some(2));
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, &[1, 2]);
+        let mut nulled = RawBufSlice::new_nulled();
+        nulled.truncate(0);
+        generic_empty_test(&nulled);
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        slice_raw_mut.truncate(1);
+        assert_eq!(slice_raw_mut.len(), Some(1));
+        assert_eq!(unsafe { slice_raw_mut.get().unwrap() }, &[1]);
+    }
+
+    #[test]
+    pub fn test_advance() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
+        unsafe { slice_raw.advance(1) };
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, &[2, 3, 4]);
+        unsafe { slice_raw.advance(5) };
+        assert_eq!(slice_raw.len(), Some(0));
+        assert_eq!(slice_raw.as_ptr(), slice.as_ptr_range().end);
+        let mut nulled = RawBufSlice::new_nulled();
+        unsafe { nulled.advance(1) };
+        generic_empty_test(&nulled);
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        unsafe { slice_raw_mut.advance(3) };
+        assert_eq!(unsafe { slice_raw_mut.get().unwrap() }, &[4]);
+        unsafe { slice_raw_mut.advance(1) };
+        assert_eq!(slice_raw_mut.len(), Some(0));
+        assert!(!slice_raw_mut.is_null());
+    }
+
+    #[test]
+    pub fn test_copy_from_slice() {
+        let mut slice = [0; 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(unsafe { slice_raw.copy_from_slice(&[1, 2, 3, 4]) }, Ok(()));
+        assert_eq!(
+            unsafe { slice_raw.copy_from_slice(&[1, 2, 3]) },
+            Err(CopyError::LengthMismatch)
+        );
+        assert_eq!(
+            unsafe { RawBufSliceMut::new_nulled().copy_from_slice(&[]) },
+            Err(CopyError::Null)
+        );
+        assert_eq!(slice, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    pub fn test_fill() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        unsafe { slice_raw.fill(0xff) };
+        unsafe { RawBufSliceMut::new_nulled().fill(0xff) };
+        assert_eq!(slice, [0xff; 4]);
+    }
+
+    #[test]
+    pub fn test_first_last() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.first() }, Some(&1));
+        assert_eq!(unsafe { slice_raw.last() }, Some(&4));
+        let empty = unsafe { RawBufSlice::new(&[]) };
+        assert!(unsafe { empty.first() }.is_none());
+        assert!(unsafe { empty.last() }.is_none());
+        let nulled = RawBufSlice::new_nulled();
+        assert!(unsafe { nulled.first() }.is_none());
+        assert!(unsafe { nulled.last() }.is_none());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(unsafe { slice_raw_mut.first() }, Some(&1));
+        assert_eq!(unsafe { slice_raw_mut.last() }, Some(&4));
+        *unsafe { slice_raw_mut.first_mut() }.unwrap() = 5;
+        *unsafe { slice_raw_mut.last_mut() }.unwrap() = 6;
+        let mut nulled_mut = RawBufSliceMut::new_nulled();
+        assert!(unsafe { nulled_mut.first_mut() }.is_none());
+        assert!(unsafe { nulled_mut.last_mut() }.is_none());
+        assert_eq!(slice, [5, 2, 3, 6]);
+    }
+
+    #[test]
+    pub fn test_iter() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let mut iter = unsafe { slice_raw.iter() };
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert!(iter.copied().eq([2, 3]));
+        assert_eq!(unsafe { slice_raw.iter() }.sum::<u8>(), 10);
+        let nulled = RawBufSlice::new_nulled();
+        let mut iter = unsafe { nulled.iter() };
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert!(unsafe { slice_raw_mut.iter() }.copied().eq([1, 2, 3, 4]));
+        let nulled = unsafe { RawBufSlice::from_raw_parts(core::ptr::null(), 4) };
+        assert_eq!(unsafe { nulled.iter() }.len(), 0);
+    }
+
+    #[test]
+    pub fn test_hash() {
+        use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+        #[derive(Default)]
+        struct TestHasher(u64);
+
+        impl Hasher for TestHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = self.0.wrapping_mul(31).wrapping_add(*byte as u64);
+                }
+            }
+        }
+
+        let build_hasher = BuildHasherDefault::<TestHasher>::default();
+        let slice_0 = [1, 2, 3, 4];
+        let slice_1 = [1, 2, 3, 4];
+        let slice_raw_0 = unsafe { RawBufSlice::new(&slice_0) };
+        let slice_raw_1 = unsafe { RawBufSlice::new(&slice_1) };
+        assert_eq!(
+            build_hasher.hash_one(slice_raw_0),
+            build_hasher.hash_one(unsafe { RawBufSlice::new(&slice_0) })
+        );
+        assert_ne!(
+            build_hasher.hash_one(slice_raw_0),
+            build_hasher.hash_one(slice_raw_1)
+        );
+        let mut slice_2 = [1, 2, 3, 4];
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice_2) };
+        assert_eq!(slice_raw_mut, unsafe { RawBufSliceMut::new(&mut slice_2) });
+        assert_eq!(
+            build_hasher.hash_one(slice_raw_mut),
+            build_hasher.hash_one(unsafe { RawBufSliceMut::new(&mut slice_2) })
+        );
+        assert_ne!(slice_raw_mut, RawBufSliceMut::new_nulled());
+    }
+
+    #[test]
+    pub fn test_eq_mut() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
+        let slice_copied = slice_raw;
+        assert_eq!(slice_copied, slice_raw);
+        slice_raw.set_null();
+        assert_ne!(slice_copied, slice_raw);
+        assert_eq!(slice_raw, RawBufSliceMut::new_nulled());
+    }
+
+    #[test]
+    pub fn test_dangling() {
+        let dangling = RawU32Slice::dangling();
+        assert!(!dangling.is_null());
+        assert_eq!(dangling.is_empty(), Some(true));
+        assert_eq!(dangling.len(), Some(0));
+        assert_eq!(unsafe { dangling.get() }, Some(&[][..]));
+        let mut dangling_mut = RawU32SliceMut::dangling();
+        assert!(!dangling_mut.is_null());
+        assert_eq!(dangling_mut.is_empty(), Some(true));
+        assert_eq!(unsafe { dangling_mut.get_mut() }, Some(&mut [][..]));
+    }
+
+    #[test]
+    pub fn test_from_ref() {
+        let mut value = 5;
+        let slice_raw = unsafe { RawBufSlice::from_ref(&value) };
+        assert_eq!(unsafe { slice_raw.get().unwrap() }, &[5]);
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::from_mut(&mut value) };
+        assert_eq!(slice_raw_mut.len(), Some(1));
+        unsafe { slice_raw_mut.get_mut().unwrap()[0] = 6 };
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    pub fn test_from_array() {
+        static TABLE: [u16; 3] = [1, 2, 3];
+        const TABLE_RAW: RawU16Slice = unsafe { RawU16Slice::from_array(&TABLE) };
+        assert_eq!(TABLE_RAW.len(), Some(3));
+        assert_eq!(unsafe { TABLE_RAW.get().unwrap() }, &TABLE);
+        let mut array = [1, 2, 3, 4];
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::from_array_mut(&mut array) };
+        assert_eq!(slice_raw_mut.len(), Some(4));
+        unsafe { slice_raw_mut.get_mut().unwrap()[0] = 5 };
+        assert_eq!(array[0], 5);
+    }
+
+    #[test]
+    pub fn test_len_or_zero() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(slice_raw.len_or_zero(), 4);
+        assert!(!slice_raw.is_empty_or_true());
+        assert_eq!(RawBufSlice::dangling().len_or_zero(), 0);
+        assert!(RawBufSlice::dangling().is_empty_or_true());
+        let nulled = unsafe { RawBufSlice::from_raw_parts(core::ptr::null(), 4) };
+        assert_eq!(nulled.len_or_zero(), 0);
+        assert!(nulled.is_empty_or_true());
+        let slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.len_or_zero(), 4);
+        assert!(!slice_raw_mut.is_empty_or_true());
+        assert_eq!(RawBufSliceMut::new_nulled().len_or_zero(), 0);
+        assert!(RawBufSliceMut::new_nulled().is_empty_or_true());
+    }
+
+    #[test]
+    pub fn test_get_range() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert!(unsafe { slice_raw.get_range(1..3).unwrap().eq_slice(&[2, 3]) });
+        assert!(unsafe { slice_raw.get_range(2..).unwrap().eq_slice(&[3, 4]) });
+        assert!(unsafe { slice_raw.get_range(..1).unwrap().eq_slice(&[1]) });
+        assert!(unsafe { slice_raw.get_range(..=1).unwrap().eq_slice(&[1, 2]) });
+        assert_eq!(unsafe { slice_raw.get_range(..) }, Some(slice_raw));
+        assert!(unsafe { slice_raw.get_range(0..5) }.is_none());
+        assert!(unsafe { slice_raw.get_range(..=4) }.is_none());
+        assert!(unsafe { slice_raw.get_range(..=usize::MAX) }.is_none());
+        assert!(unsafe { RawBufSlice::new_nulled().get_range(..) }.is_none());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        let mut range = unsafe { slice_raw_mut.get_range_mut(3..) }.unwrap();
+        unsafe { range.get_mut().unwrap()[0] = 5 };
+        assert!(unsafe { slice_raw_mut.get_range_mut(5..) }.is_none());
+        assert_eq!(slice, [1, 2, 3, 5]);
+    }
+
+    #[test]
+    pub fn test_split_first_last() {
+        let mut slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let (first, tail) = unsafe { slice_raw.split_first() }.unwrap();
+        assert_eq!(*first, 1);
+        assert!(unsafe { tail.eq_slice(&[2, 3, 4]) });
+        let (last, head) = unsafe { slice_raw.split_last() }.unwrap();
+        assert_eq!(*last, 4);
+        assert!(unsafe { head.eq_slice(&[1, 2, 3]) });
+        let single = unsafe { RawBufSlice::new(&slice[..1]) };
+        let (first, tail) = unsafe { single.split_first() }.unwrap();
+        assert_eq!(*first, 1);
+        assert_eq!(tail.len(), Some(0));
+        let empty = RawBufSlice::dangling();
+        assert!(unsafe { empty.split_first() }.is_none());
+        assert!(unsafe { empty.split_last() }.is_none());
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        let (first, tail) = unsafe { slice_raw_mut.split_first_mut() }.unwrap();
+        *first = 5;
+        assert!(unsafe { tail.eq_slice(&[2, 3, 4]) });
+        let (last, head) = unsafe { slice_raw_mut.split_last_mut() }.unwrap();
+        *last = 6;
+        assert_eq!(head.len(), Some(3));
+        assert!(unsafe { RawBufSliceMut::new_nulled().split_first_mut() }.is_none());
+        assert!(unsafe { RawBufSliceMut::new_nulled().split_last_mut() }.is_none());
+        assert_eq!(slice, [5, 2, 3, 6]);
+    }
+
+    #[test]
+    pub fn test_chunks() {
+        let slice = [1, 2, 3, 4, 5];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let mut chunks = unsafe { slice_raw.chunks(2) };
+        assert_eq!(chunks.len(), 3);
+        assert!(unsafe { chunks.next().unwrap().eq_slice(&[1, 2]) });
+        assert!(unsafe { chunks.next().unwrap().eq_slice(&[3, 4]) });
+        assert_eq!(chunks.len(), 1);
+        assert!(unsafe { chunks.next().unwrap().eq_slice(&[5]) });
+        assert!(chunks.next().is_none());
+        assert_eq!(unsafe { slice_raw.chunks(5) }.len(), 1);
+        assert_eq!(unsafe { slice_raw.chunks(6) }.len(), 1);
+        assert_eq!(unsafe { slice_raw.chunks(0) }.len(), 0);
+        assert!(unsafe { slice_raw.chunks(0) }.next().is_none());
+        let nulled = RawBufSlice::new_nulled();
+        assert_eq!(unsafe { nulled.chunks(2) }.len(), 0);
+        assert!(unsafe { nulled.chunks(2) }.next().is_none());
+    }
+
+    #[test]
+    pub fn test_contains_ptr() {
+        let mut slice: [u16; 4] = [1, 2, 3, 4];
+        let range = slice.as_ptr_range();
+        let slice_raw = unsafe { RawU16Slice::new(&slice[1..3]) };
+        assert_eq!(slice_raw.contains_ptr(range.start), Some(false));
+        assert_eq!(slice_raw.contains_ptr(&slice[1]), Some(true));
+        assert_eq!(slice_raw.contains_ptr(&slice[2]), Some(true));
+        assert_eq!(slice_raw.contains_ptr(&slice[3]), Some(false));
+        assert_eq!(slice_raw.contains_ptr(range.end), Some(false));
+        assert!(
+            RawU16Slice::new_nulled()
+                .contains_ptr(range.start)
+                .is_none()
+        );
+        assert_eq!(
+            RawU16Slice::dangling().contains_ptr(range.start),
+            Some(false)
+        );
+        let slice_raw_mut = unsafe { RawU16SliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.contains_ptr(range.start), Some(true));
+        assert_eq!(slice_raw_mut.contains_ptr(range.end), Some(false));
+        assert!(
+            RawU16SliceMut::new_nulled()
+                .contains_ptr(range.start)
+                .is_none()
+        );
+    }
+
+    #[test]
+    pub fn test_remaining_from() {
+        let mut slice: [u32; 4] = [1, 2, 3, 4];
+        let range = slice.as_ptr_range();
+        let slice_raw = unsafe { RawU32Slice::new(&slice[1..]) };
+        assert_eq!(slice_raw.remaining_from(&slice[1]), Some(3));
+        assert_eq!(slice_raw.remaining_from(&slice[3]), Some(1));
+        assert_eq!(slice_raw.remaining_from(range.end), Some(0));
+        assert!(slice_raw.remaining_from(range.start).is_none());
+        assert!(
+            slice_raw
+                .remaining_from(range.end.wrapping_add(1))
+                .is_none()
+        );
+        let misaligned = (&slice[2] as *const u32).cast::<u8>().wrapping_add(1);
+        assert!(slice_raw.remaining_from(misaligned.cast()).is_none());
+        assert!(
+            RawU32Slice::new_nulled()
+                .remaining_from(range.start)
+                .is_none()
+        );
+        let slice_raw_mut = unsafe { RawU32SliceMut::new(&mut slice) };
+        assert_eq!(slice_raw_mut.remaining_from(range.start), Some(4));
+        assert!(
+            RawU32SliceMut::new_nulled()
+                .remaining_from(range.start)
+                .is_none()
+        );
+    }
+
+    #[test]
+    pub fn test_from_uninit() {
+        let mut buf = [core::mem::MaybeUninit::<u8>::uninit(); 4];
+        let mut slice_raw = unsafe { RawBufSliceMut::from_uninit(&mut buf) };
+        assert_eq!(slice_raw.len(), Some(4));
+        unsafe { slice_raw.copy_from_slice(&[1, 2, 3, 4]).unwrap() };
+        assert_eq!(
+            unsafe { slice_raw.assume_init_get_mut() },
+            Some(&mut [1, 2, 3, 4][..])
+        );
+        assert!(unsafe { RawBufSliceMut::new_nulled().assume_init_get_mut() }.is_none());
+    }
+
+    #[test]
+    pub fn test_align_to() {
+        let words: [u32; 3] = [0x01020304, 0x05060708, 0x090a0b0c];
+        let bytes = words.as_ptr() as *const u8;
+        let slice_raw = unsafe { RawU8Slice::from_raw_parts(bytes.add(1), 10) };
+        let (prefix, middle, suffix) = unsafe { slice_raw.align_to::<u32>() };
+        assert_eq!(prefix.len(), Some(3));
+        assert_eq!(prefix.as_ptr(), unsafe { bytes.add(1) });
+        assert_eq!(middle.len(), Some(1));
+        assert_eq!(middle.as_ptr(), &words[1] as *const u32);
+        assert_eq!(suffix.len(), Some(3));
+        assert_eq!(suffix.as_ptr(), unsafe { bytes.add(8) });
+        let (prefix, middle, suffix) = unsafe { RawU8Slice::new_nulled().align_to::<u32>() };
+        assert!(prefix.is_null());
+        assert!(middle.is_null());
+        assert!(suffix.is_null());
+    }
+
+    #[test]
+    pub fn test_swap_contents() {
+        let mut ping = [1, 2, 3, 4];
+        let mut pong = [5, 6, 7, 8];
+        let mut short = [9];
+        let mut ping_raw = unsafe { RawBufSliceMut::new(&mut ping) };
+        let mut pong_raw = unsafe { RawBufSliceMut::new(&mut pong) };
+        let mut short_raw = unsafe { RawBufSliceMut::new(&mut short) };
+        assert_eq!(unsafe { ping_raw.swap_contents(&mut pong_raw) }, Ok(()));
+        assert_eq!(
+            unsafe { ping_raw.swap_contents(&mut short_raw) },
+            Err(SwapError::LengthMismatch)
+        );
+        assert_eq!(
+            unsafe { ping_raw.swap_contents(&mut RawBufSliceMut::new_nulled()) },
+            Err(SwapError::Null)
+        );
+        assert_eq!(
+            unsafe { RawBufSliceMut::new_nulled().swap_contents(&mut ping_raw) },
+            Err(SwapError::Null)
+        );
+        assert_eq!(ping, [5, 6, 7, 8]);
+        assert_eq!(pong, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    pub fn test_atomic_raw_slice() {
+        static SHARED: AtomicRawSlice<u8> = AtomicRawSlice::new_nulled();
+        static DATA: [u8; 4] = [1, 2, 3, 4];
+        static OTHER: [u8; 2] = [5, 6];
+        assert!(SHARED.take().is_null());
+        let data_raw = unsafe { RawBufSlice::new(&DATA) };
+        let other_raw = unsafe { RawBufSlice::new(&OTHER) };
+        assert_eq!(SHARED.try_store(data_raw), Ok(()));
+        assert_eq!(SHARED.try_store(other_raw), Err(other_raw));
+        let taken = SHARED.take();
+        assert!(unsafe { taken.eq_slice(&DATA) });
+        assert!(SHARED.take().is_null());
+        // The slot can be filled again after a take.
+        assert_eq!(SHARED.try_store(other_raw), Ok(()));
+        SHARED.store(data_raw);
+        assert_eq!(SHARED.take(), data_raw);
+        SHARED.store(RawBufSlice::new_nulled());
+        assert_eq!(SHARED.try_store(other_raw), Ok(()));
+        assert_eq!(SHARED.take(), other_raw);
+    }
+
+    #[test]
+    pub fn test_atomic_raw_slice_handoff() {
+        extern crate std;
+
+        static SHARED: AtomicRawSlice<u8> = AtomicRawSlice::new_nulled();
+        static DATA: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        const ROUNDS: usize = 10_000;
+        let producer = std::thread::spawn(|| {
+            for i in 0..ROUNDS {
+                // Every slice has a start offset and a length which belong together.
+                let slice = unsafe { RawBufSlice::new(&DATA[i % 4..4]) };
+                while SHARED.try_store(slice).is_err() {
+                    std::thread::yield_now();
+                }
+            }
+        });
+        let mut received = 0;
+        while received < ROUNDS {
+            let taken = SHARED.take();
+            if taken.is_null() {
+                std::thread::yield_now();
+                continue;
+            }
+            let contents = unsafe { taken.get() }.unwrap();
+            // No raw slice is skipped and every raw slice is taken exactly once.
+            assert_eq!(contents[0] as usize, received % 4);
+            assert_eq!(contents.len(), 4 - contents[0] as usize);
+            received += 1;
+        }
+        producer.join().unwrap();
+        assert!(SHARED.take().is_null());
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    pub fn test_cast_pod() {
+        let mut words: [u32; 2] = [1, 2];
+        let slice_raw = unsafe { RawU32Slice::new(&words) };
+        let bytes = slice_raw.cast_pod::<u8>().unwrap();
+        assert_eq!(bytes.len(), Some(8));
+        assert_eq!(bytes.cast_pod::<u32>(), Ok(slice_raw));
+        let bytes_unaligned = unsafe { bytes.subslice(1, 5).unwrap() };
+        assert_eq!(
+            bytes_unaligned.cast_pod::<u32>(),
+            Err(bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+        );
+        let bytes_slop = unsafe { bytes.subslice(0, 7).unwrap() };
+        assert_eq!(
+            bytes_slop.cast_pod::<u32>(),
+            Err(bytemuck::PodCastError::OutputSliceWouldHaveSlop)
+        );
+        assert!(
+            RawU32Slice::new_nulled()
+                .cast_pod::<u8>()
+                .unwrap()
+                .is_null()
+        );
+        let overflowing = unsafe { RawU32Slice::from_raw_parts(words.as_ptr(), usize::MAX) };
+        assert_eq!(
+            overflowing.cast_pod::<u8>(),
+            Err(bytemuck::PodCastError::SizeMismatch)
+        );
+        let slice_raw_mut = unsafe { RawU32SliceMut::new(&mut words) };
+        let mut bytes_mut = slice_raw_mut.cast_pod::<u8>().unwrap();
+        unsafe { bytes_mut.fill(0) };
+        assert_eq!(words, [0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy")]
+    pub fn test_cast_from_bytes() {
+        #[derive(zerocopy::FromBytes, Debug, PartialEq)]
+        #[repr(C)]
+        struct Packet {
+            id: u16,
+            len: u16,
+        }
+
+        let words: [u32; 2] = [0, 0];
+        let bytes = unsafe { RawU32Slice::new(&words) }.as_bytes();
+        let packets = bytes.cast_from_bytes::<Packet>().unwrap();
+        assert_eq!(packets.len(), Some(2));
+        assert_eq!(packets.as_ptr() as *const u32, words.as_ptr());
+        assert_eq!(
+            unsafe { bytes.subslice(1, 5).unwrap() }.cast_from_bytes::<Packet>(),
+            Err(FromBytesError::Alignment)
+        );
+        assert_eq!(
+            unsafe { bytes.subslice(0, 6).unwrap() }.cast_from_bytes::<Packet>(),
+            Err(FromBytesError::Size)
+        );
+        assert!(
+            RawU8Slice::new_nulled()
+                .cast_from_bytes::<Packet>()
+                .unwrap()
+                .is_null()
+        );
+    }
+
+    #[test]
+    pub fn test_map_len() {
+        let mut slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
+        slice_raw.map_len(|len| len - 1);
+        assert_eq!(slice_raw.len(), Some(3));
+        slice_raw.map_len(|len| len * 2);
+        assert_eq!(slice_raw.len(), Some(3));
+        let mut nulled = RawBufSlice::new_nulled();
+        nulled.map_len(|_| 1);
+        generic_empty_test(&nulled);
+        let mut slice_raw_mut = unsafe { RawBufSliceMut::new(&mut slice) };
+        slice_raw_mut.map_len(|len| len / 2);
+        assert_eq!(slice_raw_mut.len(), Some(2));
+        slice_raw_mut.map_len(|_| 10);
+        assert_eq!(slice_raw_mut.len(), Some(2));
+    }
+
+    #[test]
+    pub fn test_sync_raw_slice() {
+        static DATA: [u8; 4] = [1, 2, 3, 4];
+        static SHARED: SyncRawSlice<u8> = unsafe { SyncRawSlice::new(RawBufSlice::new(&DATA)) };
+        assert_eq!(SHARED.len(), Some(4));
+        assert!(unsafe { SHARED.inner().eq_slice(&DATA) });
+        assert_eq!(SHARED.into_inner(), unsafe { RawBufSlice::new(&DATA) });
+    }
+
+    #[test]
+    #[cfg(feature = "critical-section")]
+    pub fn test_cs_raw_slice() {
+        static SHARED: CsRawSlice<u8> = CsRawSlice::new();
+        static DATA: [u8; 4] = [1, 2, 3, 4];
+        assert!(SHARED.with(|slice| slice.is_none()));
+        unsafe { SHARED.set(RawBufSlice::new(&DATA)) };
+        assert_eq!(SHARED.with(|slice| slice.unwrap().iter().sum::<u8>()), 10);
+        SHARED.clear();
+        assert!(SHARED.with(|slice| slice.is_none()));
+
+        struct Register(u32);
+        static REGS: CsRawSlice<Register> = CsRawSlice::new();
+        static REG_DATA: [Register; 2] = [Register(1), Register(2)];
+        unsafe { REGS.set(RawSlice::new(&REG_DATA)) };
+        assert_eq!(REGS.with(|regs| regs.unwrap()[1].0), 2);
+        assert_eq!(REGS.with(|regs| regs.unwrap().len()), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn test_from_leaked_vec() {
+        let slice_raw = RawBufSlice::from_leaked_vec(alloc::vec![1, 2, 3, 4]);
+        assert_eq!(slice_raw.len(), Some(4));
+        assert!(unsafe { slice_raw.eq_slice(&[1, 2, 3, 4]) });
+    }
+
+    #[test]
+    pub fn test_debug_contents() {
+        extern crate std;
+        use std::format;
+
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let formatted = format!("{:?}", unsafe { slice_raw.debug_contents() });
+        assert!(formatted.starts_with("RawSlice {"));
+        assert!(formatted.contains("contents: [1, 2, 3, 4]"));
+        assert!(!format!("{slice_raw:?}").contains("contents"));
+        let nulled = RawBufSliceMut::new_nulled();
+        let formatted = format!("{:?}", unsafe { nulled.debug_contents() });
+        assert!(formatted.starts_with("RawSliceMut {"));
+        assert!(formatted.contains("data: 0x0"));
+        assert!(formatted.contains("len: 0"));
+    }
+
+    #[test]
+    pub fn test_ptr_eq() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let mut truncated = slice_raw;
+        truncated.truncate(2);
+        assert!(slice_raw.ptr_eq(&truncated));
+        assert_ne!(slice_raw, truncated);
+        let other = [1, 2, 3, 4];
+        assert!(!slice_raw.ptr_eq(&unsafe { RawBufSlice::new(&other) }));
+        assert!(RawBufSlice::new_nulled().ptr_eq(&RawBufSlice::new_nulled()));
+        assert!(!slice_raw.ptr_eq(&RawBufSlice::new_nulled()));
+
+        let mut buf = [0u8; 4];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let mut truncated = buf_raw;
+        truncated.truncate(1);
+        assert!(buf_raw.ptr_eq(&truncated));
+        assert!(RawBufSliceMut::new_nulled().ptr_eq(&RawBufSliceMut::new_nulled()));
+    }
+
+    #[test]
+    pub fn test_overlaps() {
+        let mut buf = [0u32; 8];
+        let mut buf_raw = unsafe { RawU32SliceMut::new(&mut buf) };
+        let (first, second) = unsafe { buf_raw.split_at_mut(4) }.unwrap();
+        assert_eq!(first.overlaps(&second), Some(false));
+        assert_eq!(second.overlaps(&first), Some(false));
+        assert_eq!(buf_raw.overlaps(&first), Some(true));
+        assert_eq!(second.overlaps(&buf_raw), Some(true));
+        let middle = unsafe { buf_raw.subslice_mut(3, 5) }.unwrap();
+        assert_eq!(middle.overlaps(&first), Some(true));
+        assert_eq!(middle.overlaps(&second), Some(true));
+        let empty = unsafe { buf_raw.subslice_mut(2, 2) }.unwrap();
+        assert_eq!(empty.overlaps(&buf_raw), Some(false));
+        assert_eq!(buf_raw.overlaps(&RawU32SliceMut::new_nulled()), None);
+    }
+
+    #[test]
+    pub fn test_rotate() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.rotate_left(2) };
+        assert_eq!(buf, [3, 4, 5, 1, 2]);
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.rotate_right(2) };
+        assert_eq!(buf, [1, 2, 3, 4, 5]);
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.rotate_left(7) };
+        assert_eq!(buf, [3, 4, 5, 1, 2]);
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.rotate_right(12) };
+        assert_eq!(buf, [1, 2, 3, 4, 5]);
+
+        let mut empty: [u8; 0] = [];
+        let mut empty_raw = unsafe { RawBufSliceMut::new(&mut empty) };
+        unsafe { empty_raw.rotate_left(3) };
+        unsafe { empty_raw.rotate_right(3) };
+        let mut nulled = RawBufSliceMut::new_nulled();
+        unsafe { nulled.rotate_left(1) };
+        unsafe { nulled.rotate_right(1) };
+    }
+
+    #[test]
+    pub fn test_from_raw_parts_aligned() {
+        let buf = [0u32; 4];
+        let slice_raw = unsafe { RawU32Slice::from_raw_parts_aligned(buf.as_ptr(), buf.len()) };
+        assert_eq!(slice_raw, Ok(unsafe { RawU32Slice::new(&buf) }));
+        let misaligned = buf.as_ptr().cast::<u8>().wrapping_add(1).cast::<u32>();
+        assert_eq!(
+            unsafe { RawU32Slice::from_raw_parts_aligned(misaligned, 1) },
+            Err(AlignError)
+        );
+        let nulled = unsafe { RawU32Slice::from_raw_parts_aligned(core::ptr::null(), 4) };
+        assert!(nulled.unwrap().is_null());
+
+        let mut buf = [0u32; 4];
+        let misaligned = buf.as_mut_ptr().cast::<u8>().wrapping_add(2).cast::<u32>();
+        assert_eq!(
+            unsafe { RawU32SliceMut::from_raw_parts_aligned(misaligned, 1) },
+            Err(AlignError)
+        );
+        let slice_raw =
+            unsafe { RawU32SliceMut::from_raw_parts_aligned(buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(slice_raw.unwrap().len(), Some(4));
+    }
+
+    #[test]
+    pub fn test_take() {
         let slice = [1, 2, 3, 4];
         let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
-        assert_eq!(slice_raw.len().unwrap(), 4);
-        assert!(!slice_raw.is_null());
-        assert!(!slice_raw.is_empty().unwrap());
-        assert_eq!(slice_raw.len().unwrap(), 4);
-        let slice_read_back = unsafe { slice_raw.get().unwrap() };
-        assert_eq!(slice_read_back, slice);
-        slice_raw.set_null();
-        generic_empty_test(&slice_raw);
+        let taken = slice_raw.take();
+        assert!(slice_raw.is_null());
+        assert_eq!(slice_raw.len(), None);
+        assert_eq!(taken.as_ptr(), slice.as_ptr());
+        assert_eq!(taken.len(), Some(4));
+        assert!(slice_raw.take().is_null());
+
+        let mut buf = [0u8; 4];
+        let buf_ptr = buf.as_mut_ptr();
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let taken = buf_raw.take();
+        assert!(buf_raw.is_null());
+        assert_eq!(taken.as_ptr(), buf_ptr.cast_const());
+        assert_eq!(taken.len(), Some(4));
     }
 
     #[test]
-    pub fn test_empty() {
-        let empty = RawBufSlice::new_nulled();
-        generic_empty_test(&empty);
+    pub fn test_replace() {
+        let first = [1, 2, 3, 4];
+        let second = [5, 6];
+        let mut slice_raw = unsafe { RawBufSlice::new(&first) };
+        let previous = unsafe { slice_raw.replace(&second) };
+        assert_eq!(previous.as_ptr(), first.as_ptr());
+        assert_eq!(previous.len(), Some(4));
+        assert_eq!(slice_raw.as_ptr(), second.as_ptr());
+        assert_eq!(slice_raw.len(), Some(2));
+        let mut nulled = RawBufSlice::new_nulled();
+        assert!(unsafe { nulled.replace(&first) }.is_null());
+
+        let mut first = [0u8; 4];
+        let mut second = [0u8; 2];
+        let first_ptr = first.as_mut_ptr();
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut first) };
+        let previous = unsafe { buf_raw.replace(&mut second) };
+        assert_eq!(previous.as_ptr(), first_ptr.cast_const());
+        assert_eq!(buf_raw.len(), Some(2));
     }
 
     #[test]
-    pub fn test_empty_mut() {
-        let mut empty = RawBufSliceMut::new_nulled();
-        generic_empty_test_mut(&mut empty);
+    pub fn test_try_len() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(slice_raw.try_len(), Ok(4));
+        assert_eq!(slice_raw.try_is_empty(), Ok(false));
+        let nulled = RawBufSlice::new_nulled();
+        assert_eq!(nulled.try_len(), Err(NullSliceError));
+        assert_eq!(nulled.try_is_empty(), Err(NullSliceError));
+
+        let mut empty: [u8; 0] = [];
+        let empty_raw = unsafe { RawBufSliceMut::new(&mut empty) };
+        assert_eq!(empty_raw.try_len(), Ok(0));
+        assert_eq!(empty_raw.try_is_empty(), Ok(true));
+        let nulled = RawBufSliceMut::new_nulled();
+        assert_eq!(nulled.try_len(), Err(NullSliceError));
+        assert_eq!(nulled.try_is_empty(), Err(NullSliceError));
     }
 
     #[test]
-    pub fn test_clonable() {
+    pub fn test_new_with_len() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new_with_len(&slice, 2) };
+        assert_eq!(slice_raw.len(), Some(2));
+        assert_eq!(unsafe { slice_raw.get() }, Some(&slice[..2]));
+        let slice_raw = unsafe { RawBufSlice::new_with_len(&slice, 8) };
+        assert_eq!(slice_raw.len(), Some(4));
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new_with_len(&mut buf, 3) };
+        assert_eq!(buf_raw.len(), Some(3));
+        unsafe { buf_raw.fill(1) };
+        assert_eq!(buf, [1, 1, 1, 0]);
+        let buf_raw = unsafe { RawBufSliceMut::new_with_len(&mut buf, usize::MAX) };
+        assert_eq!(buf_raw.len(), Some(4));
+    }
+
+    #[test]
+    pub fn test_as_non_null_slice() {
         let slice = [1, 2, 3, 4];
         let slice_raw = unsafe { RawBufSlice::new(&slice) };
-        let slice_copied = slice_raw;
-        assert_eq!(slice_copied, slice_raw);
+        let non_null = slice_raw.as_non_null_slice().unwrap();
+        assert_eq!(non_null.len(), 4);
+        assert_eq!(non_null.cast::<u8>().as_ptr().cast_const(), slice.as_ptr());
+        assert!(RawBufSlice::new_nulled().as_non_null_slice().is_none());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let mut non_null = buf_raw.as_non_null_slice().unwrap();
+        unsafe { non_null.as_mut()[1] = 5 };
+        assert_eq!(unsafe { buf_raw.get_mut() }, Some(&mut [0, 5, 0, 0][..]));
+        assert!(RawBufSliceMut::new_nulled().as_non_null_slice().is_none());
     }
 
     #[test]
-    pub fn test_basic_mut() {
-        let mut slice = [1, 2, 3, 4];
-        let mut slice_raw = unsafe { RawBufSliceMut::new(&mut slice) };
-        assert_eq!(slice_raw.len().unwrap(), 4);
-        assert!(!slice_raw.is_null());
-        assert!(!slice_raw.is_empty().unwrap());
-        assert_eq!(slice_raw.len().unwrap(), 4);
-        let slice_read_back = unsafe { slice_raw.get().unwrap() };
-        assert_eq!(slice_read_back, slice);
-        let mut_slice_read_back = unsafe { slice_raw.get_mut().unwrap() };
-        assert_eq!(slice_read_back, mut_slice_read_back);
-        mut_slice_read_back[0] = 5;
-        assert_eq!(slice[0], 5);
-        slice_raw.set_null();
-        generic_empty_test_mut(&mut slice_raw);
+    pub fn test_volatile_access() {
+        let slice = [1u32, 2, 3, 4];
+        let slice_raw = unsafe { RawU32Slice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.read_volatile(2) }, Some(3));
+        assert_eq!(unsafe { slice_raw.read_volatile(4) }, None);
+        assert_eq!(unsafe { RawU32Slice::new_nulled().read_volatile(0) }, None);
+
+        let mut buf = [0u32; 4];
+        let mut buf_raw = unsafe { RawU32SliceMut::new(&mut buf) };
+        assert_eq!(unsafe { buf_raw.write_volatile(1, 5) }, Some(()));
+        assert_eq!(unsafe { buf_raw.write_volatile(4, 5) }, None);
+        assert_eq!(unsafe { buf_raw.read_volatile(1) }, Some(5));
+        assert_eq!(unsafe { buf_raw.read_volatile(4) }, None);
+        assert_eq!(buf, [0, 5, 0, 0]);
+        let mut nulled = RawU32SliceMut::new_nulled();
+        assert_eq!(unsafe { nulled.write_volatile(0, 1) }, None);
+    }
+
+    #[test]
+    pub fn test_copy_to_slice() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let mut dst = [0u8; 4];
+        assert_eq!(unsafe { slice_raw.copy_to_slice(&mut dst) }, Ok(()));
+        assert_eq!(dst, slice);
+        let mut dst = [0u8; 3];
+        assert_eq!(
+            unsafe { slice_raw.copy_to_slice(&mut dst) },
+            Err(CopyError::LengthMismatch)
+        );
+        assert_eq!(
+            unsafe { RawBufSlice::new_nulled().copy_to_slice(&mut dst) },
+            Err(CopyError::Null)
+        );
+
+        let mut buf = [5u8; 2];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let mut dst = [0u8; 2];
+        assert_eq!(unsafe { buf_raw.copy_to_slice(&mut dst) }, Ok(()));
+        assert_eq!(dst, [5, 5]);
+    }
+
+    #[test]
+    pub fn test_position() {
+        let line = *b"AT+OK\r\n";
+        let slice_raw = unsafe { RawBufSlice::new(&line) };
+        assert_eq!(unsafe { slice_raw.position(|&b| b == b'\r') }, Some(5));
+        assert_eq!(unsafe { slice_raw.position(|&b| b == b'X') }, None);
+        assert_eq!(
+            unsafe { RawBufSlice::new_nulled().position(|_| true) },
+            None
+        );
+
+        let mut buf = [1u8, 2, 3];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        assert_eq!(unsafe { buf_raw.position(|&b| b > 1) }, Some(1));
+    }
+
+    #[test]
+    pub fn test_strip_prefix_suffix_len() {
+        let frame = [0xAA, 1, 2, 3, 0x55];
+        let frame_raw = unsafe { RawBufSlice::new(&frame) };
+        let payload = unsafe { frame_raw.strip_prefix_len(1) }.unwrap();
+        let payload = unsafe { payload.strip_suffix_len(1) }.unwrap();
+        assert!(unsafe { payload.eq_slice(&[1, 2, 3]) });
+        let empty = unsafe { frame_raw.strip_prefix_len(5) }.unwrap();
+        assert_eq!(empty.len(), Some(0));
+        assert!(unsafe { frame_raw.strip_prefix_len(6) }.is_none());
+        assert!(unsafe { frame_raw.strip_suffix_len(6) }.is_none());
+        assert!(unsafe { RawBufSlice::new_nulled().strip_prefix_len(0) }.is_none());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let mut inner = unsafe { buf_raw.strip_prefix_len(1) }.unwrap();
+        let mut inner = unsafe { inner.strip_suffix_len(1) }.unwrap();
+        unsafe { inner.fill(1) };
+        assert_eq!(buf, [0, 1, 1, 0]);
+        assert!(unsafe { RawBufSliceMut::new_nulled().strip_suffix_len(0) }.is_none());
+    }
+
+    #[test]
+    pub fn test_get_null_empty_non_empty() {
+        let slice = [1, 2, 3];
+        let non_empty = unsafe { RawBufSlice::new(&slice) };
+        let mut empty = non_empty;
+        empty.truncate(0);
+        let nulled = RawBufSlice::new_nulled();
+        assert_eq!(unsafe { nulled.get() }, None);
+        assert_eq!(unsafe { empty.get() }, Some(&[][..]));
+        let dangling = RawBufSlice::dangling();
+        assert_eq!(unsafe { dangling.get() }.map(|s| s.len()), Some(0));
+        assert_eq!(unsafe { non_empty.get() }, Some(&slice[..]));
+
+        let mut dangling = RawBufSliceMut::dangling();
+        assert_eq!(unsafe { dangling.get() }.map(|s| s.len()), Some(0));
+        assert_eq!(unsafe { dangling.get_mut() }.map(|s| s.len()), Some(0));
+        assert_eq!(unsafe { RawBufSliceMut::new_nulled().get_mut() }, None);
+    }
+
+    #[test]
+    pub fn test_get_or_empty() {
+        let slice = [1, 2, 3];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.get_or_empty() }, &slice);
+        let nulled = RawBufSlice::new_nulled();
+        assert!(unsafe { nulled.get_or_empty() }.is_empty());
+
+        let mut buf = [0u8; 2];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let contents = unsafe { buf_raw.get_mut_or_empty() };
+        contents[0] = 1;
+        assert_eq!(unsafe { buf_raw.get_or_empty() }, &[1, 0]);
+        let mut nulled = RawBufSliceMut::new_nulled();
+        assert!(unsafe { nulled.get_or_empty() }.is_empty());
+        assert!(unsafe { nulled.get_mut_or_empty() }.is_empty());
+    }
+
+    #[test]
+    pub fn test_ord() {
+        let buf = [0u8; 8];
+        let whole = unsafe { RawBufSlice::new(&buf) };
+        let front = unsafe { RawBufSlice::new(&buf[..4]) };
+        let back = unsafe { RawBufSlice::new(&buf[4..]) };
+        assert!(front < whole);
+        assert!(whole < back);
+        assert!(RawBufSlice::new_nulled() < front);
+        assert_eq!(whole.cmp(&whole), core::cmp::Ordering::Equal);
+        let mut sorted = [back, whole, front];
+        sorted.sort();
+        assert_eq!(sorted, [front, whole, back]);
+
+        let mut buf = [0u8; 8];
+        let (first, second) = buf.split_at_mut(4);
+        let first = unsafe { RawBufSliceMut::new(first) };
+        let second = unsafe { RawBufSliceMut::new(second) };
+        assert!(first < second);
+        assert_eq!(
+            second.partial_cmp(&first),
+            Some(core::cmp::Ordering::Greater)
+        );
+
+        // Neither comparing nor ordering requires any bounds on the element type.
+        #[derive(Clone, Copy)]
+        struct Register(#[allow(dead_code)] u32);
+        let regs = [Register(0), Register(1)];
+        let first = unsafe { RawSlice::new(&regs[..1]) };
+        let second = unsafe { RawSlice::new(&regs[1..]) };
+        let mut sorted = [second, first];
+        sorted.sort();
+        assert!(sorted == [first, second]);
+        assert!(first != second);
+        assert!(unsafe { RawSlice::new(&regs[..1]) } == first);
+        let mut regs = [Register(0), Register(1)];
+        let (first, second) = regs.split_at_mut(1);
+        let first = unsafe { RawSliceMut::new(first) };
+        let second = unsafe { RawSliceMut::new(second) };
+        assert!(first < second);
+        assert!(first.cmp(&second) == core::cmp::Ordering::Less);
+    }
+
+    #[test]
+    pub fn test_new_within() {
+        let region = [0u32; 8];
+        let region_start = region.as_ptr().cast::<u8>();
+        let region_len = core::mem::size_of_val(&region);
+        let slice_raw = unsafe { RawU32Slice::new_within(&region[2..6], region_start, region_len) };
+        assert_eq!(slice_raw.unwrap().len(), Some(4));
+        assert!(unsafe { RawU32Slice::new_within(&region, region_start, region_len) }.is_ok());
+        assert_eq!(
+            unsafe { RawU32Slice::new_within(&region, region_start, region_len - 1) },
+            Err(RegionError)
+        );
+        assert_eq!(
+            unsafe { RawU32Slice::new_within(&region, region_start.wrapping_add(1), region_len) },
+            Err(RegionError)
+        );
+
+        let mut buf = [0u8; 4];
+        let buf_start = buf.as_ptr();
+        assert!(unsafe { RawBufSliceMut::new_within(&mut buf, buf_start, 4) }.is_ok());
+        assert_eq!(
+            unsafe { RawBufSliceMut::new_within(&mut buf, buf_start, 3) },
+            Err(RegionError)
+        );
+    }
+
+    #[test]
+    pub fn test_shrink() {
+        let frame = [0xAA, 1, 2, 3, 0x55, 0x66];
+        let mut frame_raw = unsafe { RawBufSlice::new(&frame) };
+        unsafe { frame_raw.shrink(1, 2) };
+        assert!(unsafe { frame_raw.eq_slice(&[1, 2, 3]) });
+        unsafe { frame_raw.shrink(2, 2) };
+        assert_eq!(frame_raw.len(), Some(0));
+        let mut nulled = RawBufSlice::new_nulled();
+        unsafe { nulled.shrink(1, 1) };
+        assert!(nulled.is_null());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.shrink(1, 1) };
+        unsafe { buf_raw.fill(1) };
+        assert_eq!(buf, [0, 1, 1, 0]);
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.shrink(5, 0) };
+        assert_eq!(buf_raw.len(), Some(0));
+        assert_eq!(buf_raw.as_ptr(), buf.as_ptr().wrapping_add(4));
+    }
+
+    #[test]
+    pub fn test_as_cells() {
+        let mut buf = [0u8; 4];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let cells = unsafe { buf_raw.as_cells() }.unwrap();
+        let (first, second) = (&cells[0], &cells[3]);
+        first.set(1);
+        second.set(2);
+        cells[1].set(first.get() + second.get());
+        assert_eq!(buf, [1, 3, 0, 2]);
+        assert!(unsafe { RawBufSliceMut::new_nulled().as_cells() }.is_none());
+    }
+
+    #[test]
+    pub fn test_reborrow() {
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        {
+            let mut reborrowed = unsafe { buf_raw.reborrow() };
+            assert_eq!(reborrowed, buf_raw);
+            unsafe { reborrowed.fill(1) };
+        }
+        assert_eq!(unsafe { buf_raw.get_mut() }, Some(&mut [1, 1, 1, 1][..]));
+        let mut nulled = RawBufSliceMut::new_nulled();
+        assert!(unsafe { nulled.reborrow() }.is_null());
+    }
+
+    #[test]
+    pub fn test_max_len_slice() {
+        use embedded_dma::{ReadBuffer, WriteBuffer};
+
+        let buf = [0u16; 8];
+        let slice_raw = unsafe { RawU16Slice::new(&buf) };
+        assert!(slice_raw.with_max_len::<7>().is_none());
+        let bounded = slice_raw.with_max_len::<8>().unwrap();
+        assert_eq!(*bounded.inner(), slice_raw);
+        assert_eq!(unsafe { bounded.read_buffer() }, (buf.as_ptr(), 8));
+        assert_eq!(bounded.into_inner(), slice_raw);
+        assert!(RawU16Slice::new_nulled().with_max_len::<0>().is_some());
+
+        let mut buf = [0u8; 4];
+        let buf_ptr = buf.as_mut_ptr();
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        assert!(buf_raw.with_max_len::<2>().is_none());
+        let mut bounded = buf_raw.with_max_len::<16>().unwrap();
+        assert_eq!(unsafe { bounded.write_buffer() }, (buf_ptr, 4));
+        assert_eq!(unsafe { bounded.read_buffer() }, (buf_ptr.cast_const(), 4));
+    }
+
+    #[test]
+    pub fn test_as_dma_buffer() {
+        use embedded_dma::{ReadBuffer, WriteBuffer};
+
+        let buf = [0u32; 4];
+        let slice_raw = unsafe { RawU32Slice::new(&buf) };
+        assert_eq!(slice_raw.as_read_buffer(), (buf.as_ptr(), 4));
+        assert_eq!(slice_raw.as_read_buffer(), unsafe {
+            slice_raw.read_buffer()
+        });
+
+        let mut buf = [0u8; 4];
+        let buf_ptr = buf.as_mut_ptr();
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        assert_eq!(buf_raw.as_write_buffer(), (buf_ptr, 4));
+        assert_eq!(buf_raw.as_write_buffer(), unsafe { buf_raw.write_buffer() });
+        assert_eq!(buf_raw.as_read_buffer(), (buf_ptr.cast_const(), 4));
+        assert_eq!(buf_raw.as_read_buffer(), unsafe { buf_raw.read_buffer() });
+    }
+
+    #[test]
+    pub fn test_zeroed() {
+        let mut buf = [0xFFFF_FFFFu32; 4];
+        let mut buf_raw = unsafe { RawU32SliceMut::new(&mut buf) };
+        unsafe { buf_raw.zeroed() };
+        assert_eq!(buf, [0; 4]);
+        let mut buf = [1u8, 2, 3, 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf[1..3]) };
+        unsafe { buf_raw.zeroed() };
+        assert_eq!(buf, [1, 0, 0, 4]);
+        let mut nulled = RawBufSliceMut::new_nulled();
+        unsafe { nulled.zeroed() };
+    }
+
+    #[test]
+    pub fn test_split_at_unchecked() {
+        let slice = [1, 2, 3, 4];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        for mid in 0..=4 {
+            assert_eq!(Some(unsafe { slice_raw.split_at_unchecked(mid) }), unsafe {
+                slice_raw.split_at(mid)
+            });
+        }
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let (mut first, mut second) = unsafe { buf_raw.split_at_mut_unchecked(1) };
+        unsafe { first.fill(1) };
+        unsafe { second.fill(2) };
+        assert_eq!(buf, [1, 2, 2, 2]);
+    }
+
+    #[test]
+    pub fn test_dma_descriptor() {
+        let buf = [0u32; 4];
+        let slice_raw = unsafe { RawU32Slice::new(&buf) };
+        assert_eq!(
+            slice_raw.dma_descriptor(),
+            Some((buf.as_ptr() as usize, 16))
+        );
+        assert_eq!(RawU32Slice::new_nulled().dma_descriptor(), None);
+
+        let mut buf = [0u16; 3];
+        let buf_addr = buf.as_ptr() as usize;
+        let buf_raw = unsafe { RawU16SliceMut::new(&mut buf) };
+        assert_eq!(buf_raw.dma_descriptor(), Some((buf_addr, 6)));
+        assert_eq!(RawU16SliceMut::new_nulled().dma_descriptor(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "generation")]
+    pub fn test_gen_raw_slice() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static GENERATION: AtomicU32 = AtomicU32::new(0);
+        let buf = [1, 2, 3];
+        let slice_raw = unsafe { GenRawSlice::new(&buf, GENERATION.load(Ordering::Relaxed)) };
+        assert_eq!(slice_raw.tag(), 0);
+        assert_eq!(slice_raw.inner().len(), Some(3));
+        assert_eq!(
+            unsafe { slice_raw.get_checked(GENERATION.load(Ordering::Relaxed)) },
+            Some(&buf[..])
+        );
+        GENERATION.fetch_add(1, Ordering::Relaxed);
+        assert_eq!(
+            unsafe { slice_raw.get_checked(GENERATION.load(Ordering::Relaxed)) },
+            None
+        );
+    }
+
+    #[test]
+    pub fn test_copy_within() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        assert_eq!(unsafe { buf_raw.copy_within(1, 4, 0) }, Ok(()));
+        assert_eq!(unsafe { buf_raw.get() }, Some(&[2, 3, 4, 4, 5][..]));
+        assert_eq!(unsafe { buf_raw.copy_within(0, 3, 2) }, Ok(()));
+        assert_eq!(unsafe { buf_raw.get() }, Some(&[2, 3, 2, 3, 4][..]));
+        assert_eq!(
+            unsafe { buf_raw.copy_within(0, 3, 3) },
+            Err(CopyError::OutOfBounds)
+        );
+        assert_eq!(
+            unsafe { buf_raw.copy_within(3, 2, 0) },
+            Err(CopyError::OutOfBounds)
+        );
+        assert_eq!(
+            unsafe { buf_raw.copy_within(0, 6, 0) },
+            Err(CopyError::OutOfBounds)
+        );
+        assert_eq!(unsafe { buf_raw.copy_within(5, 5, 5) }, Ok(()));
+        assert_eq!(
+            unsafe { RawBufSliceMut::new_nulled().copy_within(0, 0, 0) },
+            Err(CopyError::Null)
+        );
+    }
+
+    #[test]
+    pub fn test_has_data() {
+        let slice = [1, 2, 3];
+        assert!(unsafe { RawBufSlice::new(&slice) }.has_data());
+        assert!(!RawBufSlice::dangling().has_data());
+        assert!(!RawBufSlice::new_nulled().has_data());
+        assert!(!unsafe { RawBufSlice::from_raw_parts(core::ptr::null(), 3) }.has_data());
+
+        let mut buf = [0u8; 2];
+        assert!(unsafe { RawBufSliceMut::new(&mut buf) }.has_data());
+        assert!(!RawBufSliceMut::dangling().has_data());
+        assert!(!RawBufSliceMut::new_nulled().has_data());
+    }
+
+    #[test]
+    pub fn test_alignment() {
+        let buf = [0u64; 2];
+        let slice_raw = unsafe { RawSlice::new(&buf) };
+        assert!(slice_raw.alignment().unwrap() >= 8);
+        let bytes = slice_raw.as_bytes();
+        let misaligned = unsafe { bytes.strip_prefix_len(2) }.unwrap();
+        assert_eq!(misaligned.alignment(), Some(2));
+        let misaligned = unsafe { bytes.strip_prefix_len(5) }.unwrap();
+        assert_eq!(misaligned.alignment(), Some(1));
+        assert_eq!(RawBufSlice::new_nulled().alignment(), None);
+
+        let mut buf = [0u32; 2];
+        let buf_raw = unsafe { RawU32SliceMut::new(&mut buf) };
+        assert!(buf_raw.alignment().unwrap() >= 4);
+        assert_eq!(RawU32SliceMut::new_nulled().alignment(), None);
+    }
+
+    #[test]
+    pub fn test_from_str() {
+        const GREETING: RawU8Slice = unsafe { RawU8Slice::from_str("hello\r\n") };
+        assert_eq!(GREETING.len(), Some(7));
+        assert!(unsafe { GREETING.eq_slice(b"hello\r\n") });
+        let empty = unsafe { RawU8Slice::from_str("") };
+        assert!(!empty.is_null());
+        assert_eq!(empty.is_empty(), Some(true));
+    }
+
+    #[test]
+    pub fn test_reverse() {
+        let mut buf = [1, 2, 3, 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.reverse() };
+        assert_eq!(buf, [4, 3, 2, 1]);
+        let mut nulled = RawBufSliceMut::new_nulled();
+        unsafe { nulled.reverse() };
+    }
+
+    #[test]
+    pub fn test_with_address_offset() {
+        let buf = [0u32; 8];
+        let slice_raw = unsafe { RawU32Slice::new(&buf[..4]) };
+        let mirror = unsafe { slice_raw.with_address_offset(16) };
+        assert_eq!(mirror.as_ptr(), buf[4..].as_ptr());
+        assert_eq!(mirror.len(), Some(4));
+        let back = unsafe { mirror.with_address_offset(-16) };
+        assert_eq!(back, slice_raw);
+        assert!(unsafe { RawU32Slice::new_nulled().with_address_offset(16) }.is_null());
+
+        let mut buf = [0u8; 4];
+        let buf_ptr = buf.as_ptr();
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf[..2]) };
+        let mirror = unsafe { buf_raw.with_address_offset(2) };
+        assert_eq!(mirror.as_ptr(), buf_ptr.wrapping_add(2));
+        assert!(unsafe { RawBufSliceMut::new_nulled().with_address_offset(2) }.is_null());
+    }
+
+    #[test]
+    pub fn test_raw_array_slice() {
+        use embedded_dma::ReadBuffer;
+
+        let fifo = [1u8, 2, 3, 4];
+        let mut array_raw = unsafe { RawArraySlice::<u8, 4>::new(&fifo) };
+        assert_eq!(array_raw.len(), Some(4));
+        assert_eq!(array_raw.capacity(), 4);
+        assert_eq!(unsafe { array_raw.get() }, Some(&fifo[..]));
+        assert_eq!(unsafe { array_raw.read_buffer() }, (fifo.as_ptr(), 4));
+        let larger = [5u8; 8];
+        unsafe { array_raw.set(&larger) };
+        assert_eq!(array_raw.len(), Some(4));
+        assert_eq!(unsafe { array_raw.get() }, Some(&larger[..4]));
+        unsafe { array_raw.set(&larger[..2]) };
+        assert_eq!(array_raw.is_empty(), Some(false));
+        assert_eq!(array_raw.as_raw_slice().len(), Some(2));
+        array_raw.set_null();
+        assert!(array_raw.is_null());
+        assert_eq!(array_raw.len(), None);
+        assert_eq!(unsafe { array_raw.get() }, None);
+        assert_eq!(array_raw, RawArraySlice::default());
+    }
+
+    #[test]
+    pub fn test_as_array() {
+        let frame = [0xAA, 0x01, 0x02, 0x55];
+        let frame_raw = unsafe { RawBufSlice::new(&frame) };
+        let header: &[u8; 4] = unsafe { frame_raw.as_array() }.unwrap();
+        assert_eq!(header, &frame);
+        assert!(unsafe { frame_raw.as_array::<3>() }.is_none());
+        let nulled = RawBufSlice::new_nulled();
+        assert!(unsafe { nulled.as_array::<0>() }.is_none());
+
+        let mut buf = [0u8; 2];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let header: &mut [u8; 2] = unsafe { buf_raw.as_array_mut() }.unwrap();
+        header[0] = 0xAA;
+        assert_eq!(unsafe { buf_raw.as_array() }, Some(&[0xAA, 0]));
+        assert!(unsafe { buf_raw.as_array_mut::<4>() }.is_none());
+        assert!(unsafe { RawBufSliceMut::new_nulled().as_array_mut::<0>() }.is_none());
+    }
+
+    #[test]
+    pub fn test_truncate_within_capacity() {
+        let mut buf = [0u8; 8];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let ptr = buf_raw.as_ptr();
+        buf_raw.truncate(4);
+        assert_eq!(buf_raw.len(), Some(4));
+        buf_raw.truncate(6);
+        assert_eq!(buf_raw.len(), Some(4));
+        assert_eq!(buf_raw.as_ptr(), ptr);
+        let mut buf_raw = unsafe { RawBufSliceMut::new_with_len(&mut buf, 6) };
+        assert_eq!(buf_raw.len(), Some(6));
+        buf_raw.truncate(2);
+        assert_eq!(buf_raw.len(), Some(2));
+    }
+
+    #[test]
+    pub fn test_try_get() {
+        fn checksum(slice: &RawBufSlice) -> Result<u8, NullSliceError> {
+            Ok(unsafe { slice.try_get() }?
+                .iter()
+                .fold(0u8, |acc, b| acc.wrapping_add(*b)))
+        }
+
+        let slice = [1, 2, 3];
+        assert_eq!(checksum(&unsafe { RawBufSlice::new(&slice) }), Ok(6));
+        assert_eq!(checksum(&RawBufSlice::new_nulled()), Err(NullSliceError));
+
+        let mut buf = [0u8; 2];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.try_get_mut() }.unwrap()[1] = 1;
+        assert_eq!(unsafe { buf_raw.try_get() }, Ok(&[0, 1][..]));
+        let mut nulled = RawBufSliceMut::new_nulled();
+        assert_eq!(unsafe { nulled.try_get() }, Err(NullSliceError));
+        assert_eq!(unsafe { nulled.try_get_mut() }, Err(NullSliceError));
+    }
+
+    #[test]
+    pub fn test_duplicate() {
+        let slice = [1, 2, 3];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let duplicate = slice_raw.duplicate();
+        assert_eq!(duplicate, slice_raw);
+        assert!(RawBufSlice::new_nulled().duplicate().is_null());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let mut duplicate = buf_raw.duplicate();
+        assert_eq!(duplicate, buf_raw);
+        let (mut first, _) = unsafe { buf_raw.split_at_mut(2) }.unwrap();
+        let (_, mut second) = unsafe { duplicate.split_at_mut(2) }.unwrap();
+        unsafe { first.fill(1) };
+        unsafe { second.fill(2) };
+        assert_eq!(buf, [1, 1, 2, 2]);
+    }
+
+    #[test]
+    pub fn test_valid_raw_slice() {
+        let slice = [1u32, 2, 3];
+        let slice_raw = unsafe { RawU32Slice::new(&slice) };
+        let valid = unsafe { slice_raw.assume_valid() };
+        assert_eq!(valid.len(), Some(3));
+        assert_eq!(valid.is_empty(), Some(false));
+        let mut sum = 0;
+        for value in &valid {
+            sum += value;
+        }
+        assert_eq!(sum, 6);
+        assert!(valid.into_iter().eq(slice.iter()));
+        let nulled = unsafe { RawU32Slice::new_nulled().assume_valid() };
+        assert_eq!(nulled.len(), None);
+        assert_eq!(nulled.into_iter().count(), 0);
+
+        let mut buf = [4u8, 5];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let valid = unsafe { buf_raw.assume_valid() };
+        assert!(valid.iter().rev().eq([5, 4].iter()));
+    }
+
+    #[test]
+    pub fn test_view() {
+        let slice = [1, 2, 3];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let view = unsafe { slice_raw.view() }.unwrap();
+        assert_eq!(view.len(), 3);
+        assert_eq!(view[1], 2);
+        assert_eq!(view.iter().sum::<u8>(), 6);
+        assert_eq!(view.as_slice(), &slice);
+        assert!(unsafe { RawBufSlice::new_nulled().view() }.is_none());
+
+        let mut buf = [4u8, 5];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let view = unsafe { buf_raw.view() }.unwrap();
+        assert_eq!(&*view, &[4, 5]);
+        assert!(unsafe { RawBufSliceMut::new_nulled().view() }.is_none());
+    }
+
+    #[test]
+    pub fn test_cross_type_eq() {
+        let mut buf = [0u8; 4];
+        let buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let downgraded = buf_raw.downgrade();
+        assert_eq!(downgraded, buf_raw);
+        assert_eq!(buf_raw, downgraded);
+        let mut truncated = downgraded;
+        truncated.truncate(2);
+        assert_ne!(truncated, buf_raw);
+        assert_ne!(buf_raw, truncated);
+        assert_eq!(RawBufSlice::new_nulled(), RawBufSliceMut::new_nulled());
+    }
+
+    #[test]
+    pub fn test_fill_with() {
+        let mut buf = [0u16; 4];
+        let mut buf_raw = unsafe { RawU16SliceMut::new(&mut buf) };
+        unsafe { buf_raw.fill_with(|i| i as u16) };
+        assert_eq!(unsafe { buf_raw.get() }, Some(&[0, 1, 2, 3][..]));
+        let mut nulled = RawU16SliceMut::new_nulled();
+        unsafe { nulled.fill_with(|_| unreachable!()) };
+    }
+
+    #[test]
+    pub fn test_raw_len() {
+        let slice = [1, 2, 3, 4];
+        let mut slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(slice_raw.raw_len(), 4);
+        unsafe { slice_raw.set_raw_len(2) };
+        assert_eq!(slice_raw.len(), Some(2));
+        unsafe { slice_raw.set_raw_len(4) };
+        assert_eq!(unsafe { slice_raw.get() }, Some(&slice[..]));
+        let nulled = unsafe { RawBufSlice::from_raw_parts(core::ptr::null(), 3) };
+        assert_eq!(nulled.raw_len(), 3);
+        assert_eq!(nulled.len(), None);
+
+        let mut buf = [0u8; 8];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        unsafe { buf_raw.set_raw_len(5) };
+        assert_eq!(buf_raw.raw_len(), 5);
+        assert_eq!(buf_raw.len(), Some(5));
+    }
+
+    #[test]
+    pub fn test_concat_adjacent() {
+        let buf = [0u32; 8];
+        let first = unsafe { RawU32Slice::new(&buf[..3]) };
+        let second = unsafe { RawU32Slice::new(&buf[3..]) };
+        let merged = first.concat_adjacent(&second).unwrap();
+        assert_eq!(merged, unsafe { RawU32Slice::new(&buf) });
+        assert!(second.concat_adjacent(&first).is_none());
+        let gap = unsafe { RawU32Slice::new(&buf[4..]) };
+        assert!(first.concat_adjacent(&gap).is_none());
+        assert!(first.concat_adjacent(&RawU32Slice::new_nulled()).is_none());
+        assert!(RawU32Slice::new_nulled().concat_adjacent(&first).is_none());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        let (first, second) = unsafe { buf_raw.split_at_mut(1) }.unwrap();
+        assert_eq!(first.concat_adjacent(&second), Some(buf_raw));
+        assert!(second.concat_adjacent(&first).is_none());
+    }
+
+    #[test]
+    pub fn test_read_write_at() {
+        let slice = [1u8, 2, 3];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        assert_eq!(unsafe { slice_raw.read_at(2) }, Some(3));
+        assert_eq!(unsafe { slice_raw.read_at(3) }, None);
+        assert_eq!(unsafe { RawBufSlice::new_nulled().read_at(0) }, None);
+
+        let mut buf = [0u8; 3];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf) };
+        assert_eq!(unsafe { buf_raw.write_at(1, 0xAA) }, Some(()));
+        assert_eq!(unsafe { buf_raw.write_at(3, 0xAA) }, None);
+        assert_eq!(unsafe { buf_raw.read_at(1) }, Some(0xAA));
+        assert_eq!(unsafe { buf_raw.read_at(3) }, None);
+        assert_eq!(buf, [0, 0xAA, 0]);
+        assert_eq!(unsafe { RawBufSliceMut::new_nulled().write_at(0, 1) }, None);
+    }
+
+    #[test]
+    pub fn test_default() {
+        let default = RawBufSlice::default();
+        let default_mut = RawBufSliceMut::default();
+        assert!(default.is_null());
+        assert!(default_mut.is_null());
+        assert!(!RawBufSlice::dangling().is_null());
+    }
+
+    #[test]
+    pub fn test_offset() {
+        let buf = [1, 2, 3, 4, 5];
+        let slice_raw = unsafe { RawBufSlice::new(&buf[1..]) };
+        let advanced = unsafe { slice_raw.offset(2) }.unwrap();
+        assert!(unsafe { advanced.eq_slice(&[4, 5]) });
+        let end = unsafe { slice_raw.offset(4) }.unwrap();
+        assert_eq!(end.len(), Some(0));
+        assert!(unsafe { slice_raw.offset(5) }.is_none());
+        let back = unsafe { slice_raw.offset(-1) }.unwrap();
+        assert_eq!(back, unsafe { RawBufSlice::new(&buf) });
+        assert_eq!(unsafe { slice_raw.offset(0) }, Some(slice_raw));
+        assert!(unsafe { RawBufSlice::new_nulled().offset(0) }.is_none());
+
+        let mut buf = [0u8; 4];
+        let mut buf_raw = unsafe { RawBufSliceMut::new(&mut buf[2..]) };
+        let mut whole = unsafe { buf_raw.offset(-2) }.unwrap();
+        unsafe { whole.fill(1) };
+        assert_eq!(buf, [1; 4]);
+        assert!(unsafe { RawBufSliceMut::new_nulled().offset(1) }.is_none());
+    }
+
+    #[test]
+    pub fn test_local_raw_slice() {
+        let cells = [core::cell::Cell::new(1u8), core::cell::Cell::new(2)];
+        let mut local = LocalRawSlice::new(unsafe { RawSlice::new(&cells) });
+        assert_eq!(local.len(), Some(2));
+        assert_eq!(unsafe { local.get() }.unwrap()[1].get(), 2);
+        local.truncate(1);
+        assert_eq!(local.len(), Some(1));
+        local.set_null();
+        assert!(local.into_inner().is_null());
+        assert!(LocalRawSlice::<u8>::default().len_or_zero() == 0);
+    }
+
+    #[test]
+    pub fn test_word_conversions() {
+        let buf = [0u32; 4];
+        let words = unsafe { RawU32Slice::new(&buf) };
+        let bytes = RawU8Slice::from(words);
+        assert_eq!(bytes.len(), Some(16));
+        assert_eq!(RawU32Slice::try_from(bytes), Ok(words));
+        let halfwords = RawU16Slice::try_from(bytes).unwrap();
+        assert_eq!(halfwords.len(), Some(8));
+        let odd = unsafe { bytes.strip_suffix_len(1) }.unwrap();
+        assert_eq!(RawU32Slice::try_from(odd), Err(FromBytesError::Size));
+        let misaligned = unsafe { bytes.strip_prefix_len(2) }.unwrap();
+        let misaligned = unsafe { misaligned.strip_suffix_len(2) }.unwrap();
+        assert_eq!(
+            RawU32Slice::try_from(misaligned),
+            Err(FromBytesError::Alignment)
+        );
+        assert!(RawU16Slice::try_from(misaligned).is_ok());
+        assert!(
+            RawU64Slice::try_from(RawU8Slice::new_nulled())
+                .unwrap()
+                .is_null()
+        );
+
+        let mut buf = [0u16; 2];
+        let buf_raw = unsafe { RawU16SliceMut::new(&mut buf) };
+        let bytes = RawU8SliceMut::from(buf_raw);
+        assert_eq!(bytes.len(), Some(4));
+        let mut words = RawU32SliceMut::try_from(bytes).unwrap();
+        unsafe { words.fill(0xFFFF_FFFF) };
+        assert_eq!(buf, [0xFFFF; 2]);
+    }
+
+    #[test]
+    pub fn test_chunks_exact() {
+        let slice = [1, 2, 3, 4, 5];
+        let slice_raw = unsafe { RawBufSlice::new(&slice) };
+        let mut chunks = unsafe { slice_raw.chunks_exact(2) };
+        assert_eq!(chunks.len(), 2);
+        assert!(unsafe { chunks.remainder().eq_slice(&[5]) });
+        assert!(unsafe { chunks.next().unwrap().eq_slice(&[1, 2]) });
+        assert!(unsafe { chunks.next().unwrap().eq_slice(&[3, 4]) });
+        assert!(chunks.next().is_none());
+        assert!(unsafe { chunks.remainder().eq_slice(&[5]) });
+        let chunks = unsafe { slice_raw.chunks_exact(5) };
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks.remainder().len(), Some(0));
+        let chunks = unsafe { slice_raw.chunks_exact(6) };
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(chunks.remainder(), slice_raw);
+        let mut chunks = unsafe { slice_raw.chunks_exact(0) };
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.remainder(), slice_raw);
+        let nulled = RawBufSlice::new_nulled();
+        let mut chunks = unsafe { nulled.chunks_exact(2) };
+        assert!(chunks.next().is_none());
+        assert!(chunks.remainder().is_null());
+    }
+
+    #[test]
+    pub fn test_find_byte() {
+        let line = unsafe { RawU8Slice::from_str("AT+OK\r\nREST\n") };
+        assert_eq!(unsafe { line.find_byte(b'\n') }, Some(6));
+        assert_eq!(unsafe { line.find_byte(b'A') }, Some(0));
+        assert_eq!(unsafe { line.find_byte(b'x') }, None);
+        let empty = RawU8Slice::dangling();
+        assert_eq!(unsafe { empty.find_byte(0) }, None);
+        assert_eq!(unsafe { RawU8Slice::new_nulled().find_byte(0) }, None);
     }
 
     fn generic_empty_test(slice: &RawBufSlice) {